@@ -0,0 +1,32 @@
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{ContractPackageHash, Key, RuntimeArgs, U256, runtime_args};
+use contract_utils::ContractStorage;
+
+use crate::error::Error;
+
+fn get_token_package_hash(payment_token: Key) -> ContractPackageHash {
+    payment_token
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(Error::InvalidContractPackageHash)
+}
+
+pub fn transfer_from(
+    storage: &impl ContractStorage,
+    payment_token: Key,
+    owner: Key,
+    recipient: Key,
+    amount: U256,
+) {
+    let token_package_hash = get_token_package_hash(payment_token);
+
+    storage.call_versioned_contract::<()>(
+        token_package_hash,
+        "transfer_from",
+        runtime_args! {
+            "owner" => owner,
+            "recipient" => recipient,
+            "amount" => amount,
+        }
+    );
+}
@@ -1,59 +1,66 @@
 use alloc::{format, string::String};
-use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{ContractPackageHash, Key, RuntimeArgs, URef, runtime_args};
+use contract_utils::ContractStorage;
 
 use crate::data;
 use crate::error::Error;
 
-fn get_cep78_package_hash() -> ContractPackageHash {
-    data::get_cep78_package_hash()
+fn get_cep78_package_hash(storage: &impl ContractStorage) -> ContractPackageHash {
+    data::get_cep78_package_hash(storage)
         .into_hash()
         .map(ContractPackageHash::new)
         .unwrap_or_revert_with(Error::InvalidContractPackageHash)
 }
 
-fn generate_metadata(count: u64) -> String {
+fn generate_metadata(storage: &impl ContractStorage, token_index: u64) -> String {
     format!(r#"{{ 
-        "name": "BoredApeYachtClub", 
-        "symbol": "BAYC", 
-        "token_uri": "ipfs://QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/{}" 
-    }}"#, count)
+        "name": "{}", 
+        "symbol": "{}", 
+        "token_uri": "{}{}{}" 
+    }}"#,
+        escape_json(&data::get_collection_name(storage)),
+        escape_json(&data::get_collection_symbol(storage)),
+        escape_json(&data::get_base_token_uri(storage)),
+        token_index,
+        escape_json(&data::get_token_uri_suffix(storage))
+    )
 }
 
-pub fn balance_of(nft_owner: Key) -> u64 {
-    let cep78_package_hash = get_cep78_package_hash();
-
-    let balance = runtime::call_versioned_contract::<u64>(
-        cep78_package_hash, 
-        None, 
-        "balance_of", 
-        runtime_args! {
-            "token_owner" => nft_owner,
+/// Escapes quotes, backslashes and control characters so the value fits in a JSON string.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
         }
-    );
-
-    return balance;
+    }
+    escaped
 }
 
-pub fn mint(nft_owner: Key, mint_count:u64) {
-    let cep78_package_hash = get_cep78_package_hash();
+pub fn mint(storage: &impl ContractStorage, nft_owner: Key, mint_count:u64) {
+    let cep78_package_hash = get_cep78_package_hash(storage);
 
-    runtime::call_versioned_contract::<(String, URef)>(
-        cep78_package_hash, 
-        None, 
+    storage.call_versioned_contract::<(String, URef)>(
+        cep78_package_hash,
         "register_owner", 
         runtime_args! {
             "token_owner" => nft_owner,
         }
     );
 
-    runtime::call_versioned_contract::<(String, Key, String)>(
-        cep78_package_hash, 
-        None, 
+    storage.call_versioned_contract::<(String, Key, String)>(
+        cep78_package_hash,
         "mint", 
         runtime_args! {
             "token_owner" => nft_owner,
-            "token_meta_data" => generate_metadata(mint_count)
+            "token_meta_data" => generate_metadata(storage, mint_count)
         }
     );
 }
\ No newline at end of file
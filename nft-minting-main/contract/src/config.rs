@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+/// A snapshot of the minter configuration returned by `get_config`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The account the ADMIN role was installed with or last handed over to, if it still has it.
+    pub admin: Option<Key>,
+    pub fund_manager: Key,
+    pub cep78_package_hash: Key,
+    /// The global CSPR fee required to mint one NFT.
+    pub mint_fee: U256,
+    pub only_whitelist: bool,
+    pub allow_mint: bool,
+    /// Whether the contract is paused at the current block time.
+    pub paused: bool,
+    pub use_treasury: bool,
+    pub metadata_frozen: bool,
+    /// Whether the installer no longer holds the access URef of the contract package.
+    pub upgrade_key_dropped: bool,
+    /// The global maximum number of NFTs per account is allowed to mint.
+    pub max_mint: u64,
+    pub max_supply: u64,
+    /// The number of NFTs minted so far.
+    pub mint_count: u64,
+}
+
+impl CLTyped for Config {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Config {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.admin.to_bytes()?);
+        result.extend(self.fund_manager.to_bytes()?);
+        result.extend(self.cep78_package_hash.to_bytes()?);
+        result.extend(self.mint_fee.to_bytes()?);
+        result.extend(self.only_whitelist.to_bytes()?);
+        result.extend(self.allow_mint.to_bytes()?);
+        result.extend(self.paused.to_bytes()?);
+        result.extend(self.use_treasury.to_bytes()?);
+        result.extend(self.metadata_frozen.to_bytes()?);
+        result.extend(self.upgrade_key_dropped.to_bytes()?);
+        result.extend(self.max_mint.to_bytes()?);
+        result.extend(self.max_supply.to_bytes()?);
+        result.extend(self.mint_count.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.admin.serialized_length()
+            + self.fund_manager.serialized_length()
+            + self.cep78_package_hash.serialized_length()
+            + self.mint_fee.serialized_length()
+            + self.only_whitelist.serialized_length()
+            + self.allow_mint.serialized_length()
+            + self.paused.serialized_length()
+            + self.use_treasury.serialized_length()
+            + self.metadata_frozen.serialized_length()
+            + self.upgrade_key_dropped.serialized_length()
+            + self.max_mint.serialized_length()
+            + self.max_supply.serialized_length()
+            + self.mint_count.serialized_length()
+    }
+}
+
+impl FromBytes for Config {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (admin, bytes) = Option::<Key>::from_bytes(bytes)?;
+        let (fund_manager, bytes) = Key::from_bytes(bytes)?;
+        let (cep78_package_hash, bytes) = Key::from_bytes(bytes)?;
+        let (mint_fee, bytes) = U256::from_bytes(bytes)?;
+        let (only_whitelist, bytes) = bool::from_bytes(bytes)?;
+        let (allow_mint, bytes) = bool::from_bytes(bytes)?;
+        let (paused, bytes) = bool::from_bytes(bytes)?;
+        let (use_treasury, bytes) = bool::from_bytes(bytes)?;
+        let (metadata_frozen, bytes) = bool::from_bytes(bytes)?;
+        let (upgrade_key_dropped, bytes) = bool::from_bytes(bytes)?;
+        let (max_mint, bytes) = u64::from_bytes(bytes)?;
+        let (max_supply, bytes) = u64::from_bytes(bytes)?;
+        let (mint_count, bytes) = u64::from_bytes(bytes)?;
+        Ok((
+            Config {
+                admin,
+                fund_manager,
+                cep78_package_hash,
+                mint_fee,
+                only_whitelist,
+                allow_mint,
+                paused,
+                use_treasury,
+                metadata_frozen,
+                upgrade_key_dropped,
+                max_mint,
+                max_supply,
+                mint_count,
+            },
+            bytes,
+        ))
+    }
+}
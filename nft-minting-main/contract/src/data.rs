@@ -1,38 +1,84 @@
-use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{Key, U256};
-use contract_utils::{Dict, set_key, get_key};
+use alloc::{string::String, vec::Vec};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{Key, URef, U256};
+use contract_utils::{ContractStorage, Dict, set_key, get_key};
+
+use crate::error::Error;
+use crate::phase::Phase;
+use crate::pricing::Pricing;
+use crate::revenue::RevenueSplit;
+use crate::role::Role;
+use crate::whitelist::WhitelistEntry;
 
 pub const NAMED_KEY_ADMIN: &str = "admin";
+pub const NAMED_KEY_PENDING_ADMIN: &str = "pending_admin";
+pub const NAMED_KEY_PENDING_ADMIN_FROM: &str = "pending_admin_from";
 pub const NAMED_KEY_FUND_MANAGER: &str = "fund_manager";
 pub const NAMED_KEY_MINT_FEE: &str = "mint_fee";
+pub const NAMED_KEY_PAYMENT_TOKENS_DICT: &str = "payment_tokens_dict";
 pub const NAMED_KEY_CEP78_PACKAGE_HASH: &str = "cep78_package_hash";
 pub const NAMED_KEY_MINT_COUNT: &str = "mint_count";
+pub const NAMED_KEY_MAX_SUPPLY: &str = "max_supply";
 pub const NAMED_KEY_MAX_MIN: &str  = "max_mint";
 pub const NAMED_KEY_WHITELIST_DICT: &str = "whitelist_dict";
+pub const NAMED_KEY_WHITELIST_ENTRIES_DICT: &str = "whitelist_entries_dict";
+pub const NAMED_KEY_MINTED_DICT: &str = "minted_dict";
+pub const NAMED_KEY_MERKLE_ROOT: &str = "merkle_root";
 pub const NAMED_KEY_ONLY_WHITELIST: &str  = "only_whitelist";
 pub const NAMED_KEY_ALLOW_MINT: &str  = "allow_mint";
+pub const NAMED_KEY_COLLECTION_NAME: &str = "collection_name";
+pub const NAMED_KEY_COLLECTION_SYMBOL: &str = "collection_symbol";
+pub const NAMED_KEY_BASE_TOKEN_URI: &str = "base_token_uri";
+pub const NAMED_KEY_TOKEN_URI_SUFFIX: &str = "token_uri_suffix";
+pub const NAMED_KEY_METADATA_FROZEN: &str = "metadata_frozen";
+pub const NAMED_KEY_SALE_PHASES: &str = "sale_phases";
+pub const NAMED_KEY_PRICING: &str = "pricing";
+pub const NAMED_KEY_AUCTION_PURSE: &str = "auction_purse";
+pub const NAMED_KEY_AUCTION_MINT_COUNT: &str = "auction_mint_count";
+pub const NAMED_KEY_AUCTION_LAST_PRICE: &str = "auction_last_price";
+pub const NAMED_KEY_AUCTION_CLEARING_PRICE: &str = "auction_clearing_price";
+pub const NAMED_KEY_REBATE_DEADLINE: &str = "rebate_deadline";
+pub const NAMED_KEY_AUCTION_BIDS_DICT: &str = "auction_bids_dict";
+pub const NAMED_KEY_TREASURY_PURSE: &str = "treasury_purse";
+pub const NAMED_KEY_USE_TREASURY: &str = "use_treasury";
+pub const NAMED_KEY_RECEIVE_ENTRY_POINT: &str = "receive_entry_point";
+pub const NAMED_KEY_PAUSED: &str = "paused";
+pub const NAMED_KEY_PAUSE_REASON: &str = "pause_reason";
+pub const NAMED_KEY_PAUSE_UNTIL: &str = "pause_until";
+pub const NAMED_KEY_REVENUE_SPLITS: &str = "revenue_splits";
+pub const NAMED_KEY_REMAINDER_PAYEE: &str = "remainder_payee";
+pub const NAMED_KEY_SCHEMA_VERSION: &str = "schema_version";
+pub const NAMED_KEY_MIGRATED_VERSION: &str = "migrated_contract_version";
+pub const NAMED_KEY_UPGRADE_KEY_DROPPED: &str = "upgrade_key_dropped";
+
+pub const NAMED_KEY_ROLE_ADMIN_DICT: &str = "role_admin_dict";
+pub const NAMED_KEY_ROLE_CONFIG_MANAGER_DICT: &str = "role_config_manager_dict";
+pub const NAMED_KEY_ROLE_WHITELIST_MANAGER_DICT: &str = "role_whitelist_manager_dict";
+pub const NAMED_KEY_ROLE_FREE_MINTER_DICT: &str = "role_free_minter_dict";
+pub const NAMED_KEY_ROLE_TREASURER_DICT: &str = "role_treasurer_dict";
+pub const NAMED_KEY_ROLE_GUARDIAN_DICT: &str = "role_guardian_dict";
 
 pub const NAMED_KEY_MINTER_CONTRACT_HASH: &str = "minter_contract_hash";
 pub const NAMED_KEY_MINTER_CONTRACT_PACKAGE_HASH: &str = "minter_contract_package_hash";
 
-pub struct Whitelist {
-    dict: Dict,
+pub struct Whitelist<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
 }
 
-impl Whitelist {
-    pub fn instance() -> Whitelist {
+impl<'a, S: ContractStorage> Whitelist<'a, S> {
+    pub fn instance(storage: &'a S) -> Whitelist<'a, S> {
         Whitelist {
-            dict: Dict::instance(NAMED_KEY_WHITELIST_DICT),
+            dict: Dict::instance(storage, NAMED_KEY_WHITELIST_DICT),
         }
     }
 
-    pub fn init() {
-        Dict::init(NAMED_KEY_WHITELIST_DICT)
+    pub fn init(storage: &S) {
+        Dict::init(storage, NAMED_KEY_WHITELIST_DICT)
     }
 
-    pub fn reset() {
-        runtime::remove_key(NAMED_KEY_WHITELIST_DICT);
-        Whitelist::init();
+    pub fn reset(storage: &S) {
+        storage.remove_named_key(NAMED_KEY_WHITELIST_DICT);
+        Whitelist::init(storage);
     }
 
     pub fn get(&self, account: &Key) -> bool {
@@ -47,66 +93,454 @@ impl Whitelist {
  
 }
 
-pub fn set_admin(admin: Key) {
-    set_key(NAMED_KEY_ADMIN, admin);
+/// Whitelisted accounts with their own allowance and expiry, kept apart from the plain
+/// whitelist so both can be used at once.
+pub struct WhitelistEntries<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
+}
+
+impl<'a, S: ContractStorage> WhitelistEntries<'a, S> {
+    pub fn instance(storage: &'a S) -> WhitelistEntries<'a, S> {
+        WhitelistEntries {
+            dict: Dict::instance(storage, NAMED_KEY_WHITELIST_ENTRIES_DICT),
+        }
+    }
+
+    pub fn init(storage: &S) {
+        Dict::init(storage, NAMED_KEY_WHITELIST_ENTRIES_DICT)
+    }
+
+    pub fn reset(storage: &S) {
+        storage.remove_named_key(NAMED_KEY_WHITELIST_ENTRIES_DICT);
+        WhitelistEntries::init(storage);
+    }
+
+    pub fn get(&self, account: &Key) -> Option<WhitelistEntry> {
+        self.dict.get_by_key(account)
+    }
+
+    /// Returns the entry of the account unless it expired at the block time.
+    pub fn get_valid(&self, account: &Key, block_time: u64) -> Option<WhitelistEntry> {
+        self.get(account).filter(|entry| entry.is_valid(block_time))
+    }
+
+    pub fn set(&self, account: &Key, entry: WhitelistEntry) {
+        self.dict.set_by_key(account, entry);
+    }
+}
+
+pub struct Minted<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
+}
+
+impl<'a, S: ContractStorage> Minted<'a, S> {
+    pub fn instance(storage: &'a S) -> Minted<'a, S> {
+        Minted {
+            dict: Dict::instance(storage, NAMED_KEY_MINTED_DICT),
+        }
+    }
+
+    pub fn init(storage: &S) {
+        Dict::init(storage, NAMED_KEY_MINTED_DICT)
+    }
+
+    pub fn get(&self, account: &Key) -> u64 {
+        self.dict
+            .get_by_key(account)
+            .unwrap_or(0)
+    }
+
+    pub fn add(&self, account: &Key, count: u64) -> Result<(), Error> {
+        let minted = self
+            .get(account)
+            .checked_add(count)
+            .ok_or(Error::MintLimitExceed)?;
+        self.dict.set_by_key(account, minted);
+        Ok(())
+    }
+}
+
+pub struct Roles<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
+}
+
+impl<'a, S: ContractStorage> Roles<'a, S> {
+    pub fn instance(storage: &'a S, role: Role) -> Roles<'a, S> {
+        Roles {
+            dict: Dict::instance(storage, role.dict_name()),
+        }
+    }
+
+    pub fn init(storage: &S) {
+        for role in Role::ALL {
+            Dict::init(storage, role.dict_name());
+        }
+    }
+
+    pub fn has(&self, account: &Key) -> bool {
+        self.dict
+            .get_by_key(account)
+            .unwrap_or(false)
+    }
+
+    pub fn grant(&self, account: &Key) {
+        self.dict.set_by_key(account, true);
+    }
+
+    pub fn revoke(&self, account: &Key) {
+        self.dict.remove_by_key::<bool>(account);
+    }
+}
+
+pub struct PaymentTokens<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
+}
+
+impl<'a, S: ContractStorage> PaymentTokens<'a, S> {
+    pub fn instance(storage: &'a S) -> PaymentTokens<'a, S> {
+        PaymentTokens {
+            dict: Dict::instance(storage, NAMED_KEY_PAYMENT_TOKENS_DICT),
+        }
+    }
+
+    pub fn init(storage: &S) {
+        Dict::init(storage, NAMED_KEY_PAYMENT_TOKENS_DICT)
+    }
+
+    pub fn get(&self, payment_token: &Key) -> Option<U256> {
+        self.dict.get_by_key(payment_token)
+    }
+
+    pub fn set(&self, payment_token: &Key, mint_fee: U256) {
+        self.dict.set_by_key(payment_token, mint_fee);
+    }
+
+    pub fn remove(&self, payment_token: &Key) {
+        self.dict.remove_by_key::<U256>(payment_token);
+    }
+}
+
+/// What each buyer paid in CSPR and how many NFTs it bought during a Dutch auction.
+pub struct AuctionBids<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
+}
+
+impl<'a, S: ContractStorage> AuctionBids<'a, S> {
+    pub fn instance(storage: &'a S) -> AuctionBids<'a, S> {
+        AuctionBids {
+            dict: Dict::instance(storage, NAMED_KEY_AUCTION_BIDS_DICT),
+        }
+    }
+
+    pub fn init(storage: &S) {
+        Dict::init(storage, NAMED_KEY_AUCTION_BIDS_DICT)
+    }
+
+    pub fn reset(storage: &S) {
+        storage.remove_named_key(NAMED_KEY_AUCTION_BIDS_DICT);
+        AuctionBids::init(storage);
+    }
+
+    pub fn get(&self, account: &Key) -> (U256, u64) {
+        self.dict
+            .get_by_key(account)
+            .unwrap_or((U256::zero(), 0))
+    }
+
+    pub fn add(&self, account: &Key, paid: U256, count: u64) {
+        let (total_paid, total_count) = self.get(account);
+        self.dict.set_by_key(account, (total_paid + paid, total_count + count));
+    }
+
+    pub fn remove(&self, account: &Key) {
+        self.dict.remove_by_key::<(U256, u64)>(account);
+    }
+}
+
+pub fn set_admin(storage: &impl ContractStorage, admin: Option<Key>) {
+    set_key(storage, NAMED_KEY_ADMIN, admin);
+}
+
+pub fn get_admin(storage: &impl ContractStorage) -> Option<Key> {
+    get_key(storage, NAMED_KEY_ADMIN).unwrap_or_revert()
+}
+
+pub fn set_pending_admin(storage: &impl ContractStorage, pending_admin: Option<Key>) {
+    set_key(storage, NAMED_KEY_PENDING_ADMIN, pending_admin);
+}
+
+pub fn get_pending_admin(storage: &impl ContractStorage) -> Option<Key> {
+    get_key(storage, NAMED_KEY_PENDING_ADMIN).unwrap_or_revert()
+}
+
+pub fn set_pending_admin_from(storage: &impl ContractStorage, pending_admin_from: Option<Key>) {
+    set_key(storage, NAMED_KEY_PENDING_ADMIN_FROM, pending_admin_from);
+}
+
+pub fn get_pending_admin_from(storage: &impl ContractStorage) -> Option<Key> {
+    get_key(storage, NAMED_KEY_PENDING_ADMIN_FROM).unwrap_or_revert()
+}
+
+pub fn set_fund_manager(storage: &impl ContractStorage, fund_manager: Key) {
+    set_key(storage, NAMED_KEY_FUND_MANAGER, fund_manager);
+}
+
+pub fn get_fund_manager(storage: &impl ContractStorage) -> Key {
+    get_key(storage, NAMED_KEY_FUND_MANAGER).unwrap_or_revert()
 }
 
-pub fn get_admin() -> Key {
-    get_key(NAMED_KEY_ADMIN).unwrap_or_revert()
+pub fn set_mint_fee(storage: &impl ContractStorage, mint_fee: U256) {
+    set_key(storage, NAMED_KEY_MINT_FEE, mint_fee);
 }
 
-pub fn set_fund_manager(fund_manager: Key) {
-    set_key(NAMED_KEY_FUND_MANAGER, fund_manager);
+pub fn get_mint_fee(storage: &impl ContractStorage) -> U256 {
+    get_key(storage, NAMED_KEY_MINT_FEE).unwrap_or_revert()
 }
 
-pub fn get_fund_manager() -> Key {
-    get_key(NAMED_KEY_FUND_MANAGER).unwrap_or_revert()
+pub fn set_cep78_package_hash(storage: &impl ContractStorage, cep78_package_hash: Key) {
+    set_key(storage, NAMED_KEY_CEP78_PACKAGE_HASH, cep78_package_hash);
 }
 
-pub fn set_mint_fee(mint_fee:U256) {
-    set_key(NAMED_KEY_MINT_FEE, mint_fee);
+pub fn get_cep78_package_hash(storage: &impl ContractStorage) -> Key {
+    get_key(storage, NAMED_KEY_CEP78_PACKAGE_HASH).unwrap_or_revert()
 }
 
-pub fn get_mint_fee() -> U256 {
-    get_key(NAMED_KEY_MINT_FEE).unwrap_or_revert()
+pub fn set_mint_count(storage: &impl ContractStorage, mint_count: u64) {
+    set_key(storage, NAMED_KEY_MINT_COUNT, mint_count);
 }
 
-pub fn set_cep78_package_hash(cep78_package_hash: Key) {
-    set_key(NAMED_KEY_CEP78_PACKAGE_HASH, cep78_package_hash);
+pub fn get_mint_count(storage: &impl ContractStorage) -> u64 {
+    get_key(storage, NAMED_KEY_MINT_COUNT).unwrap_or_revert()
 }
 
-pub fn get_cep78_package_hash() -> Key {
-    get_key(NAMED_KEY_CEP78_PACKAGE_HASH).unwrap_or_revert()
+pub fn set_max_supply(storage: &impl ContractStorage, max_supply: u64) {
+    set_key(storage, NAMED_KEY_MAX_SUPPLY, max_supply);
 }
 
-pub fn set_mint_count(mint_count: u64) {
-    set_key(NAMED_KEY_MINT_COUNT, mint_count);
+pub fn get_max_supply(storage: &impl ContractStorage) -> u64 {
+    get_key(storage, NAMED_KEY_MAX_SUPPLY).unwrap_or_revert()
 }
 
-pub fn get_mint_count() -> u64 {
-    get_key(NAMED_KEY_MINT_COUNT).unwrap_or_revert()
+pub fn set_max_mint(storage: &impl ContractStorage, max_mint: u64) {
+    set_key(storage, NAMED_KEY_MAX_MIN, max_mint);
 }
 
-pub fn set_max_mint(max_mint: u64) {
-    set_key(NAMED_KEY_MAX_MIN, max_mint);
+pub fn get_max_mint(storage: &impl ContractStorage) -> u64 {
+    get_key(storage, NAMED_KEY_MAX_MIN).unwrap_or_revert()
 }
 
-pub fn get_max_mint() -> u64 {
-    get_key(NAMED_KEY_MAX_MIN).unwrap_or_revert()
+pub fn set_only_whitelist(storage: &impl ContractStorage, only_whitelist: bool) {
+    set_key(storage, NAMED_KEY_ONLY_WHITELIST, only_whitelist);
 }
 
-pub fn set_only_whitelist(only_whitelist: bool) {
-    set_key(NAMED_KEY_ONLY_WHITELIST, only_whitelist);
+pub fn get_only_whitelist(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_ONLY_WHITELIST).unwrap_or_revert()
 }
 
-pub fn get_only_whitelist() -> bool {
-    get_key(NAMED_KEY_ONLY_WHITELIST).unwrap_or_revert()
+pub fn set_allow_mint(storage: &impl ContractStorage, allow_mint: bool) {
+    set_key(storage, NAMED_KEY_ALLOW_MINT, allow_mint);
 }
 
-pub fn set_allow_mint(allow_mint: bool) {
-    set_key(NAMED_KEY_ALLOW_MINT, allow_mint);
+pub fn get_allow_mint(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_ALLOW_MINT).unwrap_or_revert()
 }
 
-pub fn get_allow_mint() -> bool {
-    get_key(NAMED_KEY_ALLOW_MINT).unwrap_or_revert()
-}
\ No newline at end of file
+pub fn set_collection_name(storage: &impl ContractStorage, collection_name: String) {
+    set_key(storage, NAMED_KEY_COLLECTION_NAME, collection_name);
+}
+
+pub fn get_collection_name(storage: &impl ContractStorage) -> String {
+    get_key(storage, NAMED_KEY_COLLECTION_NAME).unwrap_or_revert()
+}
+
+pub fn set_collection_symbol(storage: &impl ContractStorage, collection_symbol: String) {
+    set_key(storage, NAMED_KEY_COLLECTION_SYMBOL, collection_symbol);
+}
+
+pub fn get_collection_symbol(storage: &impl ContractStorage) -> String {
+    get_key(storage, NAMED_KEY_COLLECTION_SYMBOL).unwrap_or_revert()
+}
+
+pub fn set_base_token_uri(storage: &impl ContractStorage, base_token_uri: String) {
+    set_key(storage, NAMED_KEY_BASE_TOKEN_URI, base_token_uri);
+}
+
+pub fn get_base_token_uri(storage: &impl ContractStorage) -> String {
+    get_key(storage, NAMED_KEY_BASE_TOKEN_URI).unwrap_or_revert()
+}
+
+pub fn set_token_uri_suffix(storage: &impl ContractStorage, token_uri_suffix: String) {
+    set_key(storage, NAMED_KEY_TOKEN_URI_SUFFIX, token_uri_suffix);
+}
+
+pub fn get_token_uri_suffix(storage: &impl ContractStorage) -> String {
+    get_key(storage, NAMED_KEY_TOKEN_URI_SUFFIX).unwrap_or_revert()
+}
+
+pub fn set_metadata_frozen(storage: &impl ContractStorage, metadata_frozen: bool) {
+    set_key(storage, NAMED_KEY_METADATA_FROZEN, metadata_frozen);
+}
+
+pub fn get_metadata_frozen(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_METADATA_FROZEN).unwrap_or_revert()
+}
+
+pub fn set_sale_phases(storage: &impl ContractStorage, sale_phases: Vec<Phase>) {
+    set_key(storage, NAMED_KEY_SALE_PHASES, sale_phases);
+}
+
+pub fn get_sale_phases(storage: &impl ContractStorage) -> Vec<Phase> {
+    get_key(storage, NAMED_KEY_SALE_PHASES).unwrap_or_revert()
+}
+
+pub fn set_merkle_root(storage: &impl ContractStorage, merkle_root: Option<[u8; 32]>) {
+    set_key(storage, NAMED_KEY_MERKLE_ROOT, merkle_root);
+}
+
+pub fn get_merkle_root(storage: &impl ContractStorage) -> Option<[u8; 32]> {
+    get_key(storage, NAMED_KEY_MERKLE_ROOT).unwrap_or_revert()
+}
+
+pub fn set_pricing(storage: &impl ContractStorage, pricing: Pricing) {
+    set_key(storage, NAMED_KEY_PRICING, pricing);
+}
+
+pub fn get_pricing(storage: &impl ContractStorage) -> Pricing {
+    get_key(storage, NAMED_KEY_PRICING).unwrap_or_revert()
+}
+
+pub fn set_auction_purse(storage: &impl ContractStorage, auction_purse: URef) {
+    storage.put_named_key(NAMED_KEY_AUCTION_PURSE, auction_purse.into());
+}
+
+pub fn get_auction_purse(storage: &impl ContractStorage) -> URef {
+    storage.get_named_key(NAMED_KEY_AUCTION_PURSE)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert()
+}
+
+pub fn set_auction_mint_count(storage: &impl ContractStorage, auction_mint_count: u64) {
+    set_key(storage, NAMED_KEY_AUCTION_MINT_COUNT, auction_mint_count);
+}
+
+pub fn get_auction_mint_count(storage: &impl ContractStorage) -> u64 {
+    get_key(storage, NAMED_KEY_AUCTION_MINT_COUNT).unwrap_or_revert()
+}
+
+pub fn set_auction_last_price(storage: &impl ContractStorage, auction_last_price: U256) {
+    set_key(storage, NAMED_KEY_AUCTION_LAST_PRICE, auction_last_price);
+}
+
+pub fn get_auction_last_price(storage: &impl ContractStorage) -> U256 {
+    get_key(storage, NAMED_KEY_AUCTION_LAST_PRICE).unwrap_or_revert()
+}
+
+pub fn set_auction_clearing_price(
+    storage: &impl ContractStorage,
+    auction_clearing_price: Option<U256>,
+) {
+    set_key(storage, NAMED_KEY_AUCTION_CLEARING_PRICE, auction_clearing_price);
+}
+
+pub fn get_auction_clearing_price(storage: &impl ContractStorage) -> Option<U256> {
+    get_key(storage, NAMED_KEY_AUCTION_CLEARING_PRICE).unwrap_or_revert()
+}
+
+pub fn set_rebate_deadline(storage: &impl ContractStorage, rebate_deadline: Option<u64>) {
+    set_key(storage, NAMED_KEY_REBATE_DEADLINE, rebate_deadline);
+}
+
+pub fn get_rebate_deadline(storage: &impl ContractStorage) -> Option<u64> {
+    get_key(storage, NAMED_KEY_REBATE_DEADLINE).unwrap_or_revert()
+}
+
+pub fn set_revenue_splits(storage: &impl ContractStorage, revenue_splits: Vec<RevenueSplit>) {
+    set_key(storage, NAMED_KEY_REVENUE_SPLITS, revenue_splits);
+}
+
+pub fn get_revenue_splits(storage: &impl ContractStorage) -> Vec<RevenueSplit> {
+    get_key(storage, NAMED_KEY_REVENUE_SPLITS).unwrap_or_revert()
+}
+
+pub fn set_remainder_payee(storage: &impl ContractStorage, remainder_payee: Option<Key>) {
+    set_key(storage, NAMED_KEY_REMAINDER_PAYEE, remainder_payee);
+}
+
+pub fn get_remainder_payee(storage: &impl ContractStorage) -> Option<Key> {
+    get_key(storage, NAMED_KEY_REMAINDER_PAYEE).unwrap_or_revert()
+}
+
+pub fn set_treasury_purse(storage: &impl ContractStorage, treasury_purse: URef) {
+    storage.put_named_key(NAMED_KEY_TREASURY_PURSE, treasury_purse.into());
+}
+
+pub fn get_treasury_purse(storage: &impl ContractStorage) -> URef {
+    storage.get_named_key(NAMED_KEY_TREASURY_PURSE)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert()
+}
+
+pub fn set_use_treasury(storage: &impl ContractStorage, use_treasury: bool) {
+    set_key(storage, NAMED_KEY_USE_TREASURY, use_treasury);
+}
+
+pub fn get_use_treasury(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_USE_TREASURY).unwrap_or_revert()
+}
+
+pub fn set_receive_entry_point(storage: &impl ContractStorage, receive_entry_point: String) {
+    set_key(storage, NAMED_KEY_RECEIVE_ENTRY_POINT, receive_entry_point);
+}
+
+pub fn get_receive_entry_point(storage: &impl ContractStorage) -> String {
+    get_key(storage, NAMED_KEY_RECEIVE_ENTRY_POINT).unwrap_or_revert()
+}
+
+pub fn set_paused(storage: &impl ContractStorage, paused: bool) {
+    set_key(storage, NAMED_KEY_PAUSED, paused);
+}
+
+pub fn get_paused(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_PAUSED).unwrap_or_revert()
+}
+
+pub fn set_pause_reason(storage: &impl ContractStorage, pause_reason: u32) {
+    set_key(storage, NAMED_KEY_PAUSE_REASON, pause_reason);
+}
+
+pub fn get_pause_reason(storage: &impl ContractStorage) -> u32 {
+    get_key(storage, NAMED_KEY_PAUSE_REASON).unwrap_or_revert()
+}
+
+pub fn set_pause_until(storage: &impl ContractStorage, pause_until: Option<u64>) {
+    set_key(storage, NAMED_KEY_PAUSE_UNTIL, pause_until);
+}
+
+pub fn get_pause_until(storage: &impl ContractStorage) -> Option<u64> {
+    get_key(storage, NAMED_KEY_PAUSE_UNTIL).unwrap_or_revert()
+}
+
+pub fn set_schema_version(storage: &impl ContractStorage, schema_version: u32) {
+    set_key(storage, NAMED_KEY_SCHEMA_VERSION, schema_version);
+}
+
+/// Contracts installed before schema versioning have no schema version and read as `0`.
+pub fn get_schema_version(storage: &impl ContractStorage) -> u32 {
+    get_key(storage, NAMED_KEY_SCHEMA_VERSION).unwrap_or_default()
+}
+
+pub fn set_migrated_version(storage: &impl ContractStorage, migrated_version: u32) {
+    set_key(storage, NAMED_KEY_MIGRATED_VERSION, migrated_version);
+}
+
+pub fn get_migrated_version(storage: &impl ContractStorage) -> u32 {
+    get_key(storage, NAMED_KEY_MIGRATED_VERSION).unwrap_or_default()
+}
+
+pub fn set_upgrade_key_dropped(storage: &impl ContractStorage, upgrade_key_dropped: bool) {
+    set_key(storage, NAMED_KEY_UPGRADE_KEY_DROPPED, upgrade_key_dropped);
+}
+
+pub fn get_upgrade_key_dropped(storage: &impl ContractStorage) -> bool {
+    get_key(storage, NAMED_KEY_UPGRADE_KEY_DROPPED).unwrap_or_revert()
+}
@@ -19,6 +19,27 @@ pub enum Error {
     PermissionDenied = 1012,
     UableToReadPurse = 1013,
     WrongArguments = 1014,
+    PaymentTokenNotAccepted = 1015,
+    MetadataFrozen = 1016,
+    NoActivePhase = 1017,
+    InvalidPhase = 1018,
+    SupplyExceeded = 1019,
+    InvalidMerkleProof = 1020,
+    InvalidRole = 1021,
+    NoPendingAdmin = 1022,
+    InvalidPricing = 1023,
+    NoAuction = 1024,
+    AuctionNotEnded = 1025,
+    RebatesPending = 1026,
+    NoRebate = 1027,
+    InvalidRevenueSplits = 1028,
+    InvalidRecipient = 1029,
+    Paused = 1030,
+    NotPaused = 1031,
+    AlreadyMigrated = 1032,
+    UnsupportedSchemaVersion = 1033,
+    UpgradeKeyDropped = 1034,
+    RebateClaimExpired = 1035,
 }
 
 impl From<Error> for ApiError {
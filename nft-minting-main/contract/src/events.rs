@@ -0,0 +1,143 @@
+use alloc::string::{String, ToString};
+use casper_event_standard::{Event, Schemas};
+use casper_types::{Key, U256, U512};
+use contract_utils::ContractStorage;
+
+/// Emitted when NFTs are minted.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Minted {
+    pub owner: Key,
+    pub count: u64,
+    pub first_index: u64,
+    pub paid: U256,
+    /// The CEP-18 token paid with, `None` for CSPR and free mints.
+    pub payment_token: Option<Key>,
+}
+
+/// Emitted once per airdrop, the NFTs are indexed from `first_index` in the order of the recipients.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Airdropped {
+    pub recipients: u64,
+    pub count: u64,
+    pub first_index: u64,
+}
+
+/// Emitted for every updated config field, with the old and new values rendered as strings.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ConfigChanged {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Emitted when an account is added to or removed from the whitelist.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct WhitelistUpdated {
+    pub account: Key,
+    pub value: bool,
+}
+
+/// Emitted when an account gains or loses the ADMIN role.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminChanged {
+    pub previous_admin: Option<Key>,
+    pub new_admin: Option<Key>,
+}
+
+/// Emitted when a guardian pauses the contract, until the given block time if any.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    pub guardian: Key,
+    pub reason: u32,
+    pub until: Option<u64>,
+}
+
+/// Emitted when a guardian unpauses the contract.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    pub guardian: Key,
+}
+
+/// Emitted when CSPR is withdrawn from the treasury purse.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Withdrawn {
+    pub recipient: Key,
+    pub amount: U512,
+}
+
+pub fn init(storage: &impl ContractStorage) {
+    let schemas = Schemas::new()
+        .with::<Minted>()
+        .with::<Airdropped>()
+        .with::<ConfigChanged>()
+        .with::<WhitelistUpdated>()
+        .with::<AdminChanged>()
+        .with::<Paused>()
+        .with::<Unpaused>()
+        .with::<Withdrawn>();
+    storage.init_events(schemas);
+}
+
+pub fn minted(
+    storage: &impl ContractStorage,
+    owner: Key,
+    count: u64,
+    first_index: u64,
+    paid: U256,
+    payment_token: Option<Key>,
+) {
+    storage.emit_event(Minted {
+        owner,
+        count,
+        first_index,
+        paid,
+        payment_token,
+    });
+}
+
+pub fn airdropped(storage: &impl ContractStorage, recipients: u64, count: u64, first_index: u64) {
+    storage.emit_event(Airdropped {
+        recipients,
+        count,
+        first_index,
+    });
+}
+
+pub fn config_changed<T: ToString>(storage: &impl ContractStorage, field: &str, old: T, new: T) {
+    storage.emit_event(ConfigChanged {
+        field: field.to_string(),
+        old: old.to_string(),
+        new: new.to_string(),
+    });
+}
+
+pub fn whitelist_updated(storage: &impl ContractStorage, account: Key, value: bool) {
+    storage.emit_event(WhitelistUpdated { account, value });
+}
+
+pub fn admin_changed(
+    storage: &impl ContractStorage,
+    previous_admin: Option<Key>,
+    new_admin: Option<Key>,
+) {
+    storage.emit_event(AdminChanged {
+        previous_admin,
+        new_admin,
+    });
+}
+
+pub fn paused(storage: &impl ContractStorage, guardian: Key, reason: u32, until: Option<u64>) {
+    storage.emit_event(Paused {
+        guardian,
+        reason,
+        until,
+    });
+}
+
+pub fn unpaused(storage: &impl ContractStorage, guardian: Key) {
+    storage.emit_event(Unpaused { guardian });
+}
+
+pub fn withdrawn(storage: &impl ContractStorage, recipient: Key, amount: U512) {
+    storage.emit_event(Withdrawn { recipient, amount });
+}
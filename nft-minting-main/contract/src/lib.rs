@@ -1,9 +1,22 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 extern crate alloc;
 
+pub mod cep18_utils;
 pub mod cep78_utils;
+pub mod config;
 pub mod data;
 pub mod error;
+pub mod events;
+pub mod merkle;
+pub mod migrations;
 pub mod minter;
 pub mod modifiers;
-pub mod utils;
\ No newline at end of file
+pub mod phase;
+pub mod pricing;
+pub mod purse_utils;
+pub mod revenue;
+pub mod role;
+pub mod whitelist;
+
+#[cfg(test)]
+mod tests;
@@ -7,8 +7,6 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 // External dependencies
 extern crate alloc;
 use alloc::{
-    boxed::Box, 
-    vec, 
     vec::Vec, 
     collections::BTreeSet, 
     format, 
@@ -21,45 +19,97 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    runtime_args, contracts::NamedKeys, CLType, CLValue,
-    ContractPackageHash, ContractHash, EntryPoint, EntryPointAccess, 
-    EntryPointType, EntryPoints, 
-    Group, Key, Parameter, RuntimeArgs, URef, U256
+    runtime_args, contracts::NamedKeys, ContractPackageHash, ContractHash,
+    Key, RuntimeArgs, URef, U256, U512
 };
 
 // Custom dependencies
-use contract_utils::{ContractContext, OnChainContractStorage};
+use contract_utils::{entry_points, get_optional_named_arg, ContractContext, OnChainContractStorage};
+use contract::config::Config;
 use contract::minter::MINTER;
-use contract::utils;
 use contract::error::Error;
+use contract::phase::Phase;
+use contract::pricing::{DutchAuction, Pricing};
+use contract::role::Role;
 
 const CONSTRUCTOR_GROUP: &str = "constructor";
+const MIGRATION_GROUP: &str = "migration";
+const FINALIZE_GROUP: &str = "finalize";
 
 const ARG_NAME: &str = "name";
 const ARG_DISABLE_OLD: &str = "disable_old";
+const ARG_CONTRACT_VERSION: &str = "contract_version";
+const ARG_LOCKED: &str = "locked";
+const ARG_FINALIZE: &str = "finalize";
 
 const ARG_ADMIN: &str = "admin";
+const ARG_NEW_ADMIN: &str = "new_admin";
 const ARG_FUND_MANAGER: &str = "fund_manager";
 const ARG_CEP78_PACKAGE_HASH: &str = "cep78_package_hash";
 const ARG_MINT_FEE: &str = "mint_fee";
 const ARG_ONLY_WHITELIST: &str = "only_whitelist";
 const ARG_ALLOW_MINT: &str = "allow_mint";
 const ARG_MAX_MINT: &str = "max_mint";
+const ARG_MAX_SUPPLY: &str = "max_supply";
+const ARG_COLLECTION_NAME: &str = "collection_name";
+const ARG_COLLECTION_SYMBOL: &str = "collection_symbol";
+const ARG_BASE_TOKEN_URI: &str = "base_token_uri";
+const ARG_TOKEN_URI_SUFFIX: &str = "token_uri_suffix";
+const ARG_USE_TREASURY: &str = "use_treasury";
+const ARG_RECEIVE_ENTRY_POINT: &str = "receive_entry_point";
 
 const ARG_NFT_OWMER: &str = "nft_owner";
 const ARG_COUNT: &str = "count";
 const ARG_SOURCE_PURSE: &str = "source_purse";
+const ARG_PAYMENT_TOKEN: &str = "payment_token";
+const ARG_TOKEN_MINT_FEE: &str = "token_mint_fee";
+const ARG_MERKLE_ROOT: &str = "merkle_root";
+const ARG_MERKLE_PROOF: &str = "merkle_proof";
+const ARG_MERKLE_ALLOWANCE: &str = "merkle_allowance";
+
+const ARG_ACCOUNT: &str = "account";
+const ARG_ROLE: &str = "role";
 
 const ARG_WHITELIST_ACCOUNTS: &str = "whitelist_accounts";
 const ARG_WHITELIST_VALUES: &str = "whitelist_values";
+const ARG_WHITELIST_ALLOWANCES: &str = "whitelist_allowances";
+const ARG_WHITELIST_PRICES: &str = "whitelist_prices";
+const ARG_WHITELIST_EXPIRIES: &str = "whitelist_expiries";
+
+const ARG_PHASE_START_TIMES: &str = "phase_start_times";
+const ARG_PHASE_END_TIMES: &str = "phase_end_times";
+const ARG_PHASE_MINT_FEES: &str = "phase_mint_fees";
+const ARG_PHASE_MAX_MINTS: &str = "phase_max_mints";
+const ARG_PHASE_ONLY_WHITELISTS: &str = "phase_only_whitelists";
+
+const ARG_AUCTION_START_TIME: &str = "auction_start_time";
+const ARG_AUCTION_START_PRICE: &str = "auction_start_price";
+const ARG_AUCTION_FLOOR_PRICE: &str = "auction_floor_price";
+const ARG_AUCTION_PRICE_STEP: &str = "auction_price_step";
+const ARG_AUCTION_STEP_INTERVAL: &str = "auction_step_interval";
+const ARG_AUCTION_REBATE: &str = "auction_rebate";
+
+const ARG_TIER_SUPPLIES: &str = "tier_supplies";
+const ARG_TIER_PRICES: &str = "tier_prices";
+const ARG_BASE_PRICE: &str = "base_price";
+const ARG_PRICE_INCREMENT: &str = "price_increment";
+
+const ARG_SPLIT_PAYEES: &str = "split_payees";
+const ARG_SPLIT_SHARES: &str = "split_shares";
+const ARG_REMAINDER_PAYEE: &str = "remainder_payee";
+
+const ARG_AIRDROP_RECIPIENTS: &str = "airdrop_recipients";
+const ARG_AIRDROP_COUNTS: &str = "airdrop_counts";
+
+const ARG_REASON: &str = "reason";
+const ARG_UNTIL: &str = "until";
+
+const ARG_RECIPIENT: &str = "recipient";
+const ARG_AMOUNT: &str = "amount";
 
 const ENTRY_POINT_CONSTRUCTOR: &str = "constructor";
-const ENTRY_POINT_SET_CONFIG: &str = "set_config";
-const ENTRY_POINT_FREE_MINT: &str = "free_mint";
-const ENTRY_POINT_NATIVE_MINT: &str = "native_mint"; 
-const ENTRY_POINT_SET_WHITELIST: &str = "set_whitelist";
-const ENTRY_POINT_RESET_WHITELIST: &str = "reset_whitelist";
-const ENTRY_POINT_GET_MINT_COST: &str = "get_mint_cost";
+const ENTRY_POINT_MIGRATE: &str = "migrate";
+const ENTRY_POINT_FINALIZE: &str = "finalize";
 
 const NAMED_KEY_PACKAGE_HASH_PREFIX: &str = "minter_contract_package_hash";
 const NAMED_KEY_ACCESS_UREF_PREFIX: &str = "minter_contract_access";
@@ -80,6 +130,7 @@ impl MINTER<OnChainContractStorage> for Minter {}
 
 impl Minter {
     /// Constructor for the minter contract.
+    #[allow(clippy::too_many_arguments)]
     fn constructor(
         &self,
         admin: Key,
@@ -88,7 +139,13 @@ impl Minter {
         mint_fee: U256,
         only_whitelist: bool,
         allow_mint: bool,
-        max_mint: u64
+        max_mint: u64,
+        max_supply: u64,
+        collection_name: String,
+        collection_symbol: String,
+        base_token_uri: String,
+        token_uri_suffix: String,
+        locked: bool
     ) {
         MINTER::init(
             self,
@@ -98,198 +155,550 @@ impl Minter {
             mint_fee,
             only_whitelist,
             allow_mint,
-            max_mint
+            max_mint,
+            max_supply,
+            collection_name,
+            collection_symbol,
+            base_token_uri,
+            token_uri_suffix,
+            locked
         )
     }
 }
 
-// The constructor function takes the following arguments:
-// - admin: The accountHash of admin.
-// - fund_manager: The accountHash of fund manager.
-// - cep78_package_hash: The package hash of cep78 contract.
-// - mint_fee: The fee required to mint NFT.
-// - only_whitelist: The boolean for whitelisting or non-whitelisting mode.
-// - allow_mint: The boolean for enabling and disabling minting.
-// - max_mint: The maximum number of NFTs per accout is allowed to mint.
-#[no_mangle]
-pub extern "C" fn constructor() {
-    let admin = runtime::get_named_arg::<Key>(ARG_ADMIN);
-    let fund_manager = runtime::get_named_arg::<Key>(ARG_FUND_MANAGER);
-    let cep78_package_hash = runtime::get_named_arg::<Key>(ARG_CEP78_PACKAGE_HASH);
-    let mint_fee = runtime::get_named_arg::<U256>(ARG_MINT_FEE);
-    let only_whitelist = runtime::get_named_arg::<bool>(ARG_ONLY_WHITELIST);
-    let allow_mint = runtime::get_named_arg::<bool>(ARG_ALLOW_MINT);
-    let max_mint = runtime::get_named_arg::<u64>(ARG_MAX_MINT);
+entry_points! {
+    fn get_entry_points();
+
+    // The constructor function takes the following arguments:
+    // - admin: The accountHash of admin, granted every role.
+    // - fund_manager: The accountHash of fund manager.
+    // - cep78_package_hash: The package hash of cep78 contract.
+    // - mint_fee: The fee required to mint NFT.
+    // - only_whitelist: The boolean for whitelisting or non-whitelisting mode.
+    // - allow_mint: The boolean for enabling and disabling minting.
+    // - max_mint: The maximum number of NFTs per accout is allowed to mint.
+    // - max_supply: The maximum number of NFTs that can ever be minted.
+    // - collection_name: The name written in every NFT metadata.
+    // - collection_symbol: The symbol written in every NFT metadata.
+    // - base_token_uri: The token URI prefix to which the token index is appended.
+    // - token_uri_suffix: The token URI suffix appended after the token index, e.g. ".json".
+    // - locked: The boolean telling if the package was installed without upgrades.
+    #[groups(CONSTRUCTOR_GROUP)]
+    fn constructor(
+        admin: Key = ARG_ADMIN,
+        fund_manager: Key = ARG_FUND_MANAGER,
+        cep78_package_hash: Key = ARG_CEP78_PACKAGE_HASH,
+        mint_fee: U256 = ARG_MINT_FEE,
+        only_whitelist: bool = ARG_ONLY_WHITELIST,
+        allow_mint: bool = ARG_ALLOW_MINT,
+        max_mint: u64 = ARG_MAX_MINT,
+        max_supply: u64 = ARG_MAX_SUPPLY,
+        collection_name: String = ARG_COLLECTION_NAME,
+        collection_symbol: String = ARG_COLLECTION_SYMBOL,
+        base_token_uri: String = ARG_BASE_TOKEN_URI,
+        token_uri_suffix: String = ARG_TOKEN_URI_SUFFIX,
+        locked: bool = ARG_LOCKED,
+    ) {
+        Minter::default().constructor(
+            admin,
+            fund_manager,
+            cep78_package_hash,
+            mint_fee,
+            only_whitelist,
+            allow_mint,
+            max_mint,
+            max_supply,
+            collection_name,
+            collection_symbol,
+            base_token_uri,
+            token_uri_suffix,
+            locked
+        );
+    }
 
-    Minter::default().constructor(
-        admin, 
-        fund_manager, 
-        cep78_package_hash, 
-        mint_fee,
-        only_whitelist,
-        allow_mint,
-        max_mint
-    );
-}
+    // The migrate function brings the storage of a previous version up to date, called by the
+    // upgrade session on every new contract version:
+    // - contract_version: The version added by the upgrade, migrations run once per version.
+    #[groups(MIGRATION_GROUP)]
+    fn migrate(contract_version: u32 = ARG_CONTRACT_VERSION) {
+        Minter::default().migrate(contract_version).unwrap_or_revert();
+    }
 
-// The set_config function to update state variables:
-// - admin: The accountHash of admin.
-// - fund_manager: The accountHash of fund manager.
-// - mint_fee: The fee required to mint NFT.
-// - only_whitelist: The boolean for whitelisting or non-whitelisting mode.
-// - allow_mint: The boolean for enabling and disabling minting.
-// - max_mint: The maximum number of NFTs per accout is allowed to mint.
-#[no_mangle]
-pub extern "C" fn set_config() {
-    let admin = utils::get_optional_named_arg::<Key>(ARG_ADMIN);
-    let fund_manager = utils::get_optional_named_arg::<Key>(ARG_FUND_MANAGER);
-    let mint_fee  = utils::get_optional_named_arg::<U256>(ARG_MINT_FEE);
-    let only_whitelist = utils::get_optional_named_arg::<bool>(ARG_ONLY_WHITELIST);
-    let allow_mint = utils::get_optional_named_arg::<bool>(ARG_ALLOW_MINT);
-    let max_mint = utils::get_optional_named_arg::<u64>(ARG_MAX_MINT);
+    // The finalize function records that the installer dropped the package access URef, called
+    // by the session code when it removes the URef from its named keys.
+    #[groups(FINALIZE_GROUP)]
+    fn finalize() {
+        Minter::default().finalize().unwrap_or_revert();
+    }
+
+    // The is_upgrade_key_dropped function to read if the installer no longer holds the package
+    // access URef, because the package was installed locked or finalized. Only a locked install
+    // guarantees that no version can be added.
+    fn is_upgrade_key_dropped() -> bool {
+        Minter::default().is_upgrade_key_dropped()
+    }
 
+    // The set_config function to update state variables:
+    // - fund_manager: The accountHash or contract package hash of fund manager, requires the TREASURER role.
+    // - mint_fee: The fee required to mint NFT.
+    // - only_whitelist: The boolean for whitelisting or non-whitelisting mode.
+    // - allow_mint: The boolean for enabling and disabling minting.
+    // - max_mint: The maximum number of NFTs per accout is allowed to mint.
+    // - use_treasury: The boolean for keeping CSPR proceeds in the treasury purse, requires the TREASURER role.
+    // - receive_entry_point: The entry point called on contract packages receiving CSPR, requires the TREASURER role.
+    // Updating anything other than fund_manager, use_treasury and receive_entry_point requires the CONFIG_MANAGER role.
+    fn set_config(
+        fund_manager: Option<Key> = ARG_FUND_MANAGER,
+        mint_fee: Option<U256> = ARG_MINT_FEE,
+        only_whitelist: Option<bool> = ARG_ONLY_WHITELIST,
+        allow_mint: Option<bool> = ARG_ALLOW_MINT,
+        max_mint: Option<u64> = ARG_MAX_MINT,
+        use_treasury: Option<bool> = ARG_USE_TREASURY,
+        receive_entry_point: Option<String> = ARG_RECEIVE_ENTRY_POINT,
+    ) {
         Minter::default().set_config(
-            admin,
             fund_manager,
             mint_fee,
             only_whitelist,
             allow_mint,
-            max_mint
+            max_mint,
+            use_treasury,
+            receive_entry_point
         ).unwrap_or_revert();
-}
+    }
 
-// The free_mint function to freely mint NFTs by admin.
-// - nft_owner: The owner of the NFT.
-// - count: The total number of NFTs to mint.
-#[no_mangle]
-pub extern "C" fn free_mint() {
-    let nft_owner = runtime::get_named_arg::<Key>(ARG_NFT_OWMER);
-    let count = runtime::get_named_arg::<u64>(ARG_COUNT);
-    Minter::default().free_mint(nft_owner, count).unwrap_or_revert();
-}
+    // The grant_role function to grant a role to an account, requires the ADMIN role.
+    // - role: The role to grant.
+    // - account: The key of the account.
+    fn grant_role(role: Role = ARG_ROLE, account: Key = ARG_ACCOUNT) {
+        Minter::default().grant_role(role, account).unwrap_or_revert();
+    }
 
-// The set_whitelist function to whitelist user.
-// - accounts: The array of accountHashes.
-// - values: The array of boolean againts accountHashes.
-#[no_mangle]
-pub extern "C" fn set_whitelist() {
-    let accounts = runtime::get_named_arg::<Vec<Key>>(ARG_WHITELIST_ACCOUNTS);
-    let values = runtime::get_named_arg::<Vec<bool>>(ARG_WHITELIST_VALUES);
-    Minter::default().set_whitelist(accounts, values).unwrap_or_revert();
-}
+    // The revoke_role function to revoke a role from an account, requires the ADMIN role.
+    // - role: The role to revoke.
+    // - account: The key of the account.
+    fn revoke_role(role: Role = ARG_ROLE, account: Key = ARG_ACCOUNT) {
+        Minter::default().revoke_role(role, account).unwrap_or_revert();
+    }
 
-// The native_mint function to mint NFTs by users in-exchange of CSPR tokens.
-// - nft_owner: The owner of the NFT.
-// - count: The total number of NFTs to mint.
-// - source_purse: The source_purse address from which CSPR will be charged.
-#[no_mangle]
-pub extern "C" fn native_mint() {
-    let nft_owner = runtime::get_named_arg::<Key>(ARG_NFT_OWMER);
-    let count = runtime::get_named_arg::<u64>(ARG_COUNT);
-    let source_purse = runtime::get_named_arg::<URef>(ARG_SOURCE_PURSE);
-    Minter::default().native_mint(nft_owner, count, source_purse).unwrap_or_revert();
-}
+    // The renounce_role function to give up a role held by the caller.
+    // - role: The role to renounce.
+    fn renounce_role(role: Role = ARG_ROLE) {
+        Minter::default().renounce_role(role).unwrap_or_revert();
+    }
 
-// The reset_whitelist function to clear previous record and set new whitelist users record.
-// - accounts: The array of accountHashes.
-// - values: The array of boolean againts accountHashes.
-#[no_mangle]
-pub extern "C" fn reset_whitelist() {
-    let accounts = runtime::get_named_arg::<Vec<Key>>(ARG_WHITELIST_ACCOUNTS);
-    let values = runtime::get_named_arg::<Vec<bool>>(ARG_WHITELIST_VALUES);
-    Minter::default().reset_whitelist(accounts, values).unwrap_or_revert();
-}
+    // The has_role function to read if an account has a role.
+    // - role: The role to check.
+    // - account: The key of the account.
+    fn has_role(role: Role = ARG_ROLE, account: Key = ARG_ACCOUNT) -> bool {
+        Minter::default().has_role(role, account)
+    }
 
-// The get_mint_cost function to read the cost of minting NFTs.
-// - count: The number of NFTs to be mint.
-#[no_mangle]
-pub extern "C" fn get_mint_cost() {
-    let count = runtime::get_named_arg::<u64>(ARG_COUNT);
-    let mint_cost = Minter::default().get_mint_cost(count);
-    runtime::ret(CLValue::from_t(mint_cost).unwrap());
+    // The transfer_admin function to propose handing the caller's ADMIN role over to another account.
+    // - new_admin: The key of the account that has to accept the ADMIN role.
+    fn transfer_admin(new_admin: Key = ARG_NEW_ADMIN) {
+        Minter::default().transfer_admin(new_admin).unwrap_or_revert();
+    }
+
+    // The accept_admin function to complete the pending admin transfer by the pending admin.
+    fn accept_admin() {
+        Minter::default().accept_admin().unwrap_or_revert();
+    }
+
+    // The cancel_admin_transfer function to drop the pending admin transfer.
+    fn cancel_admin_transfer() {
+        Minter::default().cancel_admin_transfer().unwrap_or_revert();
+    }
+
+    // The get_pending_admin function to read the account that can accept the ADMIN role.
+    fn get_pending_admin() -> Option<Key> {
+        Minter::default().get_pending_admin()
+    }
+
+    // The set_metadata function to update the NFT metadata template until it is frozen:
+    // - collection_name: The name written in every NFT metadata.
+    // - collection_symbol: The symbol written in every NFT metadata.
+    // - base_token_uri: The token URI prefix to which the token index is appended.
+    // - token_uri_suffix: The token URI suffix appended after the token index.
+    fn set_metadata(
+        collection_name: Option<String> = ARG_COLLECTION_NAME,
+        collection_symbol: Option<String> = ARG_COLLECTION_SYMBOL,
+        base_token_uri: Option<String> = ARG_BASE_TOKEN_URI,
+        token_uri_suffix: Option<String> = ARG_TOKEN_URI_SUFFIX,
+    ) {
+        Minter::default().set_metadata(
+            collection_name,
+            collection_symbol,
+            base_token_uri,
+            token_uri_suffix
+        ).unwrap_or_revert();
+    }
+
+    // The freeze_metadata function to permanently lock the NFT metadata template.
+    fn freeze_metadata() {
+        Minter::default().freeze_metadata().unwrap_or_revert();
+    }
+
+    // The free_mint function to freely mint NFTs by admin.
+    // - nft_owner: The owner of the NFT.
+    // - count: The total number of NFTs to mint.
+    fn free_mint(
+        nft_owner: Key = ARG_NFT_OWMER,
+        count: u64 = ARG_COUNT,
+    ) {
+        Minter::default().free_mint(nft_owner, count).unwrap_or_revert();
+    }
+
+    // The airdrop function to freely mint NFTs to many owners at once by free minters.
+    // The lists describe one recipient per index.
+    // - airdrop_recipients: The owners of the NFTs.
+    // - airdrop_counts: The number of NFTs to mint to each owner.
+    // Returns the total number of NFTs minted.
+    fn airdrop(
+        recipients: Vec<Key> = ARG_AIRDROP_RECIPIENTS,
+        counts: Vec<u64> = ARG_AIRDROP_COUNTS,
+    ) -> u64 {
+        Minter::default().airdrop(recipients, counts).unwrap_or_revert()
+    }
+
+    // The set_whitelist function to whitelist user.
+    // - accounts: The array of accountHashes.
+    // - values: The array of boolean againts accountHashes.
+    fn set_whitelist(
+        accounts: Vec<Key> = ARG_WHITELIST_ACCOUNTS,
+        values: Vec<bool> = ARG_WHITELIST_VALUES,
+    ) {
+        Minter::default().set_whitelist(accounts, values).unwrap_or_revert();
+    }
+
+    // The set_whitelist_entries function to whitelist users with their own minting terms.
+    // The lists describe one entry per index.
+    // - whitelist_accounts: The array of accountHashes.
+    // - whitelist_allowances: The optional max mint of each account, the phase max mint when none.
+    // - whitelist_prices: The optional CSPR price of one NFT for each account.
+    // - whitelist_expiries: The optional block time in milliseconds at which each entry expires.
+    fn set_whitelist_entries(
+        accounts: Vec<Key> = ARG_WHITELIST_ACCOUNTS,
+        allowances: Vec<Option<u64>> = ARG_WHITELIST_ALLOWANCES,
+        prices: Vec<Option<U256>> = ARG_WHITELIST_PRICES,
+        expiries: Vec<Option<u64>> = ARG_WHITELIST_EXPIRIES,
+    ) {
+        Minter::default()
+            .set_whitelist_entries(accounts, allowances, prices, expiries)
+            .unwrap_or_revert();
+    }
+
+    // The native_mint function to mint NFTs by users in-exchange of CSPR tokens.
+    // - nft_owner: The owner of the NFT.
+    // - count: The total number of NFTs to mint.
+    // - source_purse: The source_purse address from which CSPR will be charged.
+    // - merkle_proof: The optional Merkle proof of the nft_owner allowlist leaf.
+    // - merkle_allowance: The optional mint allowance carried by the nft_owner leaf.
+    // Returns the amount charged from the source_purse.
+    fn native_mint(
+        nft_owner: Key = ARG_NFT_OWMER,
+        count: u64 = ARG_COUNT,
+        source_purse: URef = ARG_SOURCE_PURSE,
+        merkle_proof: Option<Vec<[u8; 32]>> = ARG_MERKLE_PROOF,
+        merkle_allowance: Option<u64> = ARG_MERKLE_ALLOWANCE,
+    ) -> U512 {
+        Minter::default().native_mint(
+            nft_owner,
+            count,
+            source_purse,
+            merkle_proof,
+            merkle_allowance
+        ).unwrap_or_revert()
+    }
+
+    // The reset_whitelist function to clear previous record and set new whitelist users record.
+    // - accounts: The array of accountHashes.
+    // - values: The array of boolean againts accountHashes.
+    fn reset_whitelist(
+        accounts: Vec<Key> = ARG_WHITELIST_ACCOUNTS,
+        values: Vec<bool> = ARG_WHITELIST_VALUES,
+    ) {
+        Minter::default().reset_whitelist(accounts, values).unwrap_or_revert();
+    }
+
+    // The get_mint_cost function to read the cost of minting NFTs.
+    // - count: The number of NFTs to be mint.
+    // - account: The optional NFT owner, whose whitelist entry price applies.
+    fn get_mint_cost(
+        count: u64 = ARG_COUNT,
+        account: Option<Key> = ARG_ACCOUNT,
+    ) -> U256 {
+        Minter::default().get_mint_cost(count, account).unwrap_or_revert()
+    }
+
+    // The get_remaining_supply function to read how many NFTs can still be minted.
+    fn get_remaining_supply() -> u64 {
+        Minter::default().get_remaining_supply()
+    }
+
+    // The set_merkle_root function to set the root of the Merkle allowlist.
+    // - merkle_root: The root hash of the allowlist tree, the allowlist is removed when omitted.
+    fn set_merkle_root(merkle_root: Option<[u8; 32]> = ARG_MERKLE_ROOT) {
+        Minter::default().set_merkle_root(merkle_root).unwrap_or_revert();
+    }
+
+    // The minted_by function to read how many NFTs have been minted to an account.
+    // - account: The key of NFT owner.
+    fn minted_by(account: Key = ARG_ACCOUNT) -> u64 {
+        Minter::default().minted_by(account)
+    }
+
+    // The get_config function to read the admin, fund manager, fees, flags, caps and supply.
+    fn get_config() -> Config {
+        Minter::default().get_config()
+    }
+
+    // The is_whitelisted function to read if an account is whitelisted.
+    // - account: The key of NFT owner.
+    fn is_whitelisted(account: Key = ARG_ACCOUNT) -> bool {
+        Minter::default().is_whitelisted(account)
+    }
+
+    // The remaining_for function to read how many more NFTs an account can mint in the active
+    // sale phase.
+    // - account: The key of NFT owner.
+    fn remaining_for(account: Key = ARG_ACCOUNT) -> u64 {
+        Minter::default().remaining_for(account)
+    }
+
+    // The set_sale_phases function to replace the sale schedule. The lists describe one phase
+    // per index and phases must be ordered by time without overlapping. Empty lists remove the
+    // schedule so the global mint_fee, max_mint and only_whitelist apply again.
+    // - phase_start_times: The block times in milliseconds at which the phases start.
+    // - phase_end_times: The block times in milliseconds at which the phases end.
+    // - phase_mint_fees: The fees required to mint NFT in each phase.
+    // - phase_max_mints: The maximum number of NFTs per account in each phase, counting the NFTs
+    //   the account minted in earlier phases.
+    // - phase_only_whitelists: The booleans for whitelisting mode in each phase.
+    fn set_sale_phases(
+        start_times: Vec<u64> = ARG_PHASE_START_TIMES,
+        end_times: Vec<u64> = ARG_PHASE_END_TIMES,
+        mint_fees: Vec<U256> = ARG_PHASE_MINT_FEES,
+        max_mints: Vec<u64> = ARG_PHASE_MAX_MINTS,
+        only_whitelists: Vec<bool> = ARG_PHASE_ONLY_WHITELISTS,
+    ) {
+        Minter::default()
+            .set_sale_phases(start_times, end_times, mint_fees, max_mints, only_whitelists)
+            .unwrap_or_revert();
+    }
+
+    // The get_active_phase function to read the sale phase active at the current block time.
+    // Returns None when the sale schedule has no phase running.
+    fn get_active_phase() -> Option<Phase> {
+        Minter::default().get_active_phase().ok()
+    }
+
+    // The token_mint function to mint NFTs by users in-exchange of CEP-18 tokens.
+    // The caller must approve the minter package to spend the mint cost beforehand.
+    // - nft_owner: The owner of the NFT.
+    // - count: The total number of NFTs to mint.
+    // - payment_token: The package hash of the accepted CEP-18 token.
+    // - merkle_proof: The optional Merkle proof of the nft_owner allowlist leaf.
+    // - merkle_allowance: The optional mint allowance carried by the nft_owner leaf.
+    fn token_mint(
+        nft_owner: Key = ARG_NFT_OWMER,
+        count: u64 = ARG_COUNT,
+        payment_token: Key = ARG_PAYMENT_TOKEN,
+        merkle_proof: Option<Vec<[u8; 32]>> = ARG_MERKLE_PROOF,
+        merkle_allowance: Option<u64> = ARG_MERKLE_ALLOWANCE,
+    ) {
+        Minter::default().token_mint(
+            nft_owner,
+            count,
+            payment_token,
+            merkle_proof,
+            merkle_allowance
+        ).unwrap_or_revert();
+    }
+
+    // The set_payment_token function to accept a CEP-18 token as payment or update its fee.
+    // - payment_token: The package hash of the CEP-18 token.
+    // - token_mint_fee: The fee per NFT in terms of the token.
+    fn set_payment_token(
+        payment_token: Key = ARG_PAYMENT_TOKEN,
+        token_mint_fee: U256 = ARG_TOKEN_MINT_FEE,
+    ) {
+        Minter::default().set_payment_token(payment_token, token_mint_fee).unwrap_or_revert();
+    }
+
+    // The remove_payment_token function to stop accepting a CEP-18 token as payment.
+    // - payment_token: The package hash of the CEP-18 token.
+    fn remove_payment_token(payment_token: Key = ARG_PAYMENT_TOKEN) {
+        Minter::default().remove_payment_token(payment_token).unwrap_or_revert();
+    }
+
+    // The get_token_mint_cost function to read the cost of minting NFTs with a CEP-18 token.
+    // - payment_token: The package hash of the CEP-18 token.
+    // - count: The number of NFTs to be mint.
+    fn get_token_mint_cost(
+        payment_token: Key = ARG_PAYMENT_TOKEN,
+        count: u64 = ARG_COUNT,
+    ) -> U256 {
+        Minter::default()
+            .get_token_mint_cost(payment_token, count)
+            .unwrap_or_revert()
+    }
+
+    // The set_dutch_auction function to sell NFTs for CSPR at a price descending with block time.
+    // - auction_start_time: The block time in milliseconds from which the price starts dropping.
+    // - auction_start_price: The price of one NFT when the auction starts.
+    // - auction_floor_price: The lowest price of one NFT.
+    // - auction_price_step: The amount the price drops by at every step.
+    // - auction_step_interval: The milliseconds between two price drops.
+    // - auction_rebate: Whether buyers can claim back what they paid above the clearing price.
+    fn set_dutch_auction(
+        start_time: u64 = ARG_AUCTION_START_TIME,
+        start_price: U256 = ARG_AUCTION_START_PRICE,
+        floor_price: U256 = ARG_AUCTION_FLOOR_PRICE,
+        price_step: U256 = ARG_AUCTION_PRICE_STEP,
+        step_interval: u64 = ARG_AUCTION_STEP_INTERVAL,
+        rebate: bool = ARG_AUCTION_REBATE,
+    ) {
+        let auction = DutchAuction {
+            start_time,
+            start_price,
+            floor_price,
+            price_step,
+            step_interval,
+            rebate,
+        };
+        Minter::default().set_pricing(Pricing::DutchAuction(auction)).unwrap_or_revert();
+    }
+
+    // The end_auction function to end the Dutch auction and go back to the phase mint fee.
+    // With rebates the last price paid becomes the clearing price.
+    fn end_auction() {
+        Minter::default().end_auction().unwrap_or_revert();
+    }
+
+    // The claim_rebate function to send the caller what it paid above the clearing price.
+    // Returns the amount refunded.
+    fn claim_rebate() -> U512 {
+        Minter::default().claim_rebate().unwrap_or_revert()
+    }
+
+    // The get_rebate function to read the rebate an account can claim.
+    // - account: The key of the buyer.
+    fn get_rebate(account: Key = ARG_ACCOUNT) -> U256 {
+        Minter::default().get_rebate(account)
+    }
+
+    // The sweep_rebates function to move the rebates left unclaimed past the claim deadline.
+    // - recipient: The accountHash or contract package hash receiving the CSPR.
+    // Returns the amount swept.
+    fn sweep_rebates(recipient: Key = ARG_RECIPIENT) -> U512 {
+        Minter::default().sweep_rebates(recipient).unwrap_or_revert()
+    }
+
+    // The set_fixed_pricing function to go back to the mint fee of the active sale phase.
+    fn set_fixed_pricing() {
+        Minter::default().set_pricing(Pricing::Fixed).unwrap_or_revert();
+    }
+
+    // The set_tiered_pricing function to price NFTs by tiers of supply sold. The lists describe
+    // one tier per index, ordered by supply, and NFTs minted past the last tier keep its price.
+    // - tier_supplies: The mint counts up to which each tier price applies.
+    // - tier_prices: The price of one NFT in each tier.
+    fn set_tiered_pricing(
+        supplies: Vec<u64> = ARG_TIER_SUPPLIES,
+        prices: Vec<U256> = ARG_TIER_PRICES,
+    ) {
+        Minter::default().set_tiered_pricing(supplies, prices).unwrap_or_revert();
+    }
+
+    // The set_linear_pricing function to raise the price with every NFT sold.
+    // - base_price: The price of the first NFT of the collection.
+    // - price_increment: The amount the price rises by with every NFT minted.
+    fn set_linear_pricing(
+        base_price: U256 = ARG_BASE_PRICE,
+        price_increment: U256 = ARG_PRICE_INCREMENT,
+    ) {
+        Minter::default().set_pricing(Pricing::Linear {
+            base_price,
+            price_increment,
+        }).unwrap_or_revert();
+    }
+
+    // The set_revenue_splits function to share the primary sale proceeds among payees. The lists
+    // describe one payee per index. Empty lists send the whole proceeds to the fund manager again.
+    // - split_payees: The account hashes or contract package hashes of the payees.
+    // - split_shares: The share of each payee in basis points, summing to 10,000.
+    // - remainder_payee: The payee receiving the rounding dust, required with payees.
+    fn set_revenue_splits(
+        payees: Vec<Key> = ARG_SPLIT_PAYEES,
+        shares: Vec<u32> = ARG_SPLIT_SHARES,
+        remainder_payee: Option<Key> = ARG_REMAINDER_PAYEE,
+    ) {
+        Minter::default()
+            .set_revenue_splits(payees, shares, remainder_payee)
+            .unwrap_or_revert();
+    }
+
+    // The withdraw function to move CSPR out of the treasury purse.
+    // - recipient: The accountHash or contract package hash receiving the CSPR.
+    // - amount: The amount in motes, the whole treasury balance when omitted.
+    // Returns the amount withdrawn.
+    fn withdraw(
+        recipient: Key = ARG_RECIPIENT,
+        amount: Option<U512> = ARG_AMOUNT,
+    ) -> U512 {
+        Minter::default().withdraw(recipient, amount).unwrap_or_revert()
+    }
+
+    // The get_treasury_balance function to read the CSPR held in the treasury purse.
+    fn get_treasury_balance() -> U512 {
+        Minter::default().get_treasury_balance()
+    }
+
+    // The pause function to halt every state changing entry point but unpause.
+    // - reason: The code telling why the contract is paused.
+    // - until: The optional block time in milliseconds at which the pause lifts by itself.
+    fn pause(
+        reason: u32 = ARG_REASON,
+        until: Option<u64> = ARG_UNTIL,
+    ) {
+        Minter::default().pause(reason, until).unwrap_or_revert();
+    }
+
+    // The unpause function to resume the paused contract.
+    fn unpause() {
+        Minter::default().unpause().unwrap_or_revert();
+    }
+
+    // The is_paused function to read if the contract is paused at the current block time.
+    fn is_paused() -> bool {
+        Minter::default().is_paused()
+    }
 }
 
-// Get all entry points
-fn get_entry_points() -> EntryPoints {
-    let mut entry_points = EntryPoints::new();
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_CONSTRUCTOR,
-        vec![
-            Parameter::new(ARG_ADMIN, CLType::Key),
-            Parameter::new(ARG_FUND_MANAGER, CLType::Key),
-            Parameter::new(ARG_CEP78_PACKAGE_HASH, CLType::Key),
-            Parameter::new(ARG_MINT_FEE, CLType::U256),
-            Parameter::new(ARG_ONLY_WHITELIST, CLType::Bool),
-            Parameter::new(ARG_ALLOW_MINT, CLType::Bool),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Groups(vec![Group::new(CONSTRUCTOR_GROUP)]),
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_SET_CONFIG,
-        vec![
-            Parameter::new(ARG_ADMIN, CLType::Key),
-            Parameter::new(ARG_FUND_MANAGER, CLType::Key),
-            Parameter::new(ARG_MINT_FEE, CLType::U256),
-            Parameter::new(ARG_ONLY_WHITELIST, CLType::Bool),
-            Parameter::new(ARG_ALLOW_MINT, CLType::Bool),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_FREE_MINT,
-        vec![
-            Parameter::new(ARG_NFT_OWMER, CLType::Key),
-            Parameter::new(ARG_COUNT, CLType::U64),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_NATIVE_MINT,
-        vec![
-            Parameter::new(ARG_NFT_OWMER, CLType::Key),
-            Parameter::new(ARG_COUNT, CLType::U64),
-            Parameter::new(ARG_SOURCE_PURSE, CLType::URef),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_SET_WHITELIST,
-        vec![
-            Parameter::new(ARG_WHITELIST_ACCOUNTS, CLType::List(Box::new(CLType::Key))),
-            Parameter::new(ARG_WHITELIST_VALUES, CLType::List(Box::new(CLType::Bool))),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_RESET_WHITELIST,
-        vec![
-            Parameter::new(ARG_WHITELIST_ACCOUNTS, CLType::List(Box::new(CLType::Key))),
-            Parameter::new(ARG_WHITELIST_VALUES, CLType::List(Box::new(CLType::Bool))),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points.add_entry_point(EntryPoint::new(
-        ENTRY_POINT_GET_MINT_COST,
-        vec![
-            Parameter::new(ARG_COUNT, CLType::U64),
-        ],
-        CLType::U256,
-        EntryPointAccess::Public,
-        EntryPointType::Contract,
-    ));
-    entry_points
+
+// Calls an entry point of the contract restricted to the group with an access URef that only
+// lives for the call. Packages installed before the group existed get it on first use.
+fn call_with_group_access(
+    contract_package_hash: ContractPackageHash,
+    contract_hash: ContractHash,
+    group: &str,
+    entry_point: &str,
+    args: RuntimeArgs,
+) {
+    let access: URef =
+    storage::provision_contract_user_group_uref(contract_package_hash, group)
+        .or_else(|_| {
+            storage::create_contract_user_group(contract_package_hash, group, 1, Default::default())
+                .map(|mut urefs| urefs.pop().unwrap_or_revert())
+        })
+        .unwrap_or_revert();
+
+    let _: () = runtime::call_contract(contract_hash, entry_point, args);
+    let mut urefs = BTreeSet::new();
+    urefs.insert(access);
+    storage::remove_contract_user_group_urefs(contract_package_hash, group, urefs)
+        .unwrap_or_revert();
 }
 
 // Install contract function
@@ -301,15 +710,33 @@ fn install_contract(name: &str) {
     let only_whitelist = runtime::get_named_arg::<bool>(ARG_ONLY_WHITELIST);
     let allow_mint = runtime::get_named_arg::<bool>(ARG_ALLOW_MINT);
     let max_mint = runtime::get_named_arg::<u64>(ARG_MAX_MINT);
+    let max_supply = runtime::get_named_arg::<u64>(ARG_MAX_SUPPLY);
+    let collection_name = runtime::get_named_arg::<String>(ARG_COLLECTION_NAME);
+    let collection_symbol = runtime::get_named_arg::<String>(ARG_COLLECTION_SYMBOL);
+    let base_token_uri = runtime::get_named_arg::<String>(ARG_BASE_TOKEN_URI);
+    let token_uri_suffix = get_optional_named_arg::<String>(ARG_TOKEN_URI_SUFFIX)
+        .unwrap_or_default();
+    let locked = get_optional_named_arg::<bool>(ARG_LOCKED).unwrap_or_default();
 
     let package_hash_key_name = format!("{NAMED_KEY_PACKAGE_HASH_PREFIX}_{name}");
     let access_uref_key_name = format!("{NAMED_KEY_ACCESS_UREF_PREFIX}_{name}");
-    let (contract_hash, contract_version) = storage::new_contract(
-        get_entry_points(),
-        None,
-        Some(package_hash_key_name.clone()),
-        Some(access_uref_key_name.clone()),
-    );
+    // A locked package keeps no access URef, the one it was created with only lives for
+    // this deploy.
+    let (contract_hash, contract_version) = if locked {
+        storage::new_locked_contract(
+            get_entry_points(),
+            None,
+            Some(package_hash_key_name.clone()),
+            None,
+        )
+    } else {
+        storage::new_contract(
+            get_entry_points(),
+            None,
+            Some(package_hash_key_name.clone()),
+            Some(access_uref_key_name),
+        )
+    };
 
     let package_hash = runtime::get_key(&package_hash_key_name)
         .unwrap_or_revert()
@@ -325,6 +752,12 @@ fn install_contract(name: &str) {
         ARG_ONLY_WHITELIST => only_whitelist,
         ARG_ALLOW_MINT => allow_mint,
         ARG_MAX_MINT => max_mint,
+        ARG_MAX_SUPPLY => max_supply,
+        ARG_COLLECTION_NAME => collection_name,
+        ARG_COLLECTION_SYMBOL => collection_symbol,
+        ARG_BASE_TOKEN_URI => base_token_uri,
+        ARG_TOKEN_URI_SUFFIX => token_uri_suffix,
+        ARG_LOCKED => locked,
     };
     let constructor_access: URef =
     storage::create_contract_user_group(package_hash, CONSTRUCTOR_GROUP, 1, Default::default())
@@ -350,6 +783,10 @@ fn install_contract(name: &str) {
 
 // Upgrade contract function
 fn upgrade_contract(name: &str, disable_old: bool) {
+    if !runtime::has_key(&format!("{NAMED_KEY_ACCESS_UREF_PREFIX}_{name}")) {
+        runtime::revert(Error::UpgradeKeyDropped);
+    }
+
     let contract_package_hash = runtime::get_key(&format!("{NAMED_KEY_PACKAGE_HASH_PREFIX}_{name}"))
         .unwrap_or_revert()
         .into_hash()
@@ -368,6 +805,14 @@ fn upgrade_contract(name: &str, disable_old: bool) {
         NamedKeys::new()
     );
 
+    call_with_group_access(
+        contract_package_hash,
+        contract_hash,
+        MIGRATION_GROUP,
+        ENTRY_POINT_MIGRATE,
+        runtime_args! { ARG_CONTRACT_VERSION => contract_version },
+    );
+
     if disable_old {
         storage::disable_contract_version(contract_package_hash, previous_contract_hash)
             .unwrap_or_revert();
@@ -383,14 +828,50 @@ fn upgrade_contract(name: &str, disable_old: bool) {
     );
 }
 
+// Finalize contract function, dropping the installer's package access URef
+fn finalize_contract(name: &str) {
+    let access_uref_key_name = format!("{NAMED_KEY_ACCESS_UREF_PREFIX}_{name}");
+    if !runtime::has_key(&access_uref_key_name) {
+        runtime::revert(Error::UpgradeKeyDropped);
+    }
+
+    let contract_package_hash = runtime::get_key(&format!("{NAMED_KEY_PACKAGE_HASH_PREFIX}_{name}"))
+        .unwrap_or_revert()
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(Error::MissingPackageHashForUpgrade);
+
+    let contract_hash = runtime::get_key(&format!("{NAMED_KEY_CONTRACT_HASH_PREFIX}_{name}"))
+        .unwrap_or_revert()
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(Error::MissingContractHashForUpgrade);
 
+    // The package access URef cannot be revoked, only this account's copy of it dropped. Group
+    // URefs never outlive a call, and the groups stay as the entry points still name them.
+    call_with_group_access(
+        contract_package_hash,
+        contract_hash,
+        FINALIZE_GROUP,
+        ENTRY_POINT_FINALIZE,
+        RuntimeArgs::new(),
+    );
+    runtime::remove_key(&access_uref_key_name);
+}
+
+// The session code installs the minter under `name`, upgrades it when the package already
+// exists, or finalizes it when `finalize` is set.
 #[no_mangle]
 pub extern "C" fn call() {
     let name = runtime::get_named_arg::<String>(ARG_NAME);
-    let disable_old = runtime::get_named_arg::<bool>(ARG_DISABLE_OLD);
+    let finalize = get_optional_named_arg::<bool>(ARG_FINALIZE).unwrap_or_default();
 
-    match runtime::get_key(&format!("{NAMED_KEY_ACCESS_UREF_PREFIX}_{name}")) {
+    match runtime::get_key(&format!("{NAMED_KEY_PACKAGE_HASH_PREFIX}_{name}")) {
+        Some(_) if finalize => {
+            finalize_contract(&name);
+        }
         Some(_) => {
+            let disable_old = runtime::get_named_arg::<bool>(ARG_DISABLE_OLD);
             upgrade_contract(&name, disable_old);
         }
         None => {
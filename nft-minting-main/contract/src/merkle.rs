@@ -0,0 +1,40 @@
+use alloc::vec::Vec;
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{bytesrepr::ToBytes, Key};
+use contract_utils::ContractStorage;
+
+/// Computes the allowlist leaf of an account, optionally bound to a mint allowance.
+///
+/// The leaf is `blake2b(key_bytes)` or `blake2b(key_bytes ++ allowance_u64_le)` where
+/// `key_bytes` is the serialized `Key`.
+pub fn leaf(storage: &impl ContractStorage, account: &Key, allowance: Option<u64>) -> [u8; 32] {
+    let mut bytes: Vec<u8> = account.to_bytes().unwrap_or_revert();
+    if let Some(allowance) = allowance {
+        bytes.extend(allowance.to_le_bytes());
+    }
+    storage.blake2b(&bytes)
+}
+
+/// Verifies that `leaf` belongs to the tree with the given `root`.
+///
+/// Each level hashes the sorted pair of nodes, i.e. `blake2b(min(a, b) ++ max(a, b))`,
+/// so the proof doesn't need to carry the position of the siblings.
+pub fn verify(
+    storage: &impl ContractStorage,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    proof: &[[u8; 32]],
+) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend(left);
+        bytes.extend(right);
+        storage.blake2b(&bytes)
+    });
+    computed == root
+}
@@ -0,0 +1,87 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{Key, U256};
+use contract_utils::{get_key, ContractStorage};
+
+use crate::data::{self, AuctionBids, Minted, PaymentTokens, Roles, WhitelistEntries};
+use crate::error::Error;
+use crate::events;
+use crate::minter::DEFAULT_RECEIVE_ENTRY_POINT;
+use crate::pricing::Pricing;
+use crate::role::Role;
+
+/// The storage layout written by `MINTER::init` of this version. Bump it together with a new
+/// arm in `migrate_to` whenever a version adds, removes or reshapes named keys.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The collection metadata the first release wrote into every NFT it minted.
+const FIRST_RELEASE_COLLECTION_NAME: &str = "BoredApeYachtClub";
+const FIRST_RELEASE_COLLECTION_SYMBOL: &str = "BAYC";
+const FIRST_RELEASE_BASE_TOKEN_URI: &str =
+    "ipfs://QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/";
+
+/// Brings the storage from schema `version - 1` up to schema `version`.
+///
+/// Migrations are applied in order by `MINTER::migrate`, so each arm may rely on every
+/// earlier one having run.
+pub fn migrate_to(storage: &impl ContractStorage, version: u32) -> Result<(), Error> {
+    match version {
+        // Schema 1 is the first versioned layout, everything `MINTER::init` writes.
+        1 => {
+            migrate_first_release(storage);
+            Ok(())
+        }
+        _ => Err(Error::UnsupportedSchemaVersion),
+    }
+}
+
+/// Migrates the unversioned layout of the first release, which only stored the admin as a
+/// plain `Key`, the fund manager, the CEP-78 package hash, the mint fee and count, the max
+/// mint, the whitelist flags and the whitelist.
+///
+/// The new keys keep the terms the first release enforced: no supply cap, the fixed mint
+/// fee, its collection metadata and the admin holding every role. The first release capped
+/// mints by CEP-78 balance, so `Minted` starts counting from the upgrade.
+fn migrate_first_release(storage: &impl ContractStorage) {
+    let admin: Key = get_key(storage, data::NAMED_KEY_ADMIN).unwrap_or_revert();
+    storage.remove_named_key(data::NAMED_KEY_ADMIN);
+    data::set_admin(storage, Some(admin));
+    data::set_pending_admin(storage, None);
+    data::set_pending_admin_from(storage, None);
+    data::set_max_supply(storage, u64::MAX);
+    data::set_collection_name(storage, FIRST_RELEASE_COLLECTION_NAME.to_string());
+    data::set_collection_symbol(storage, FIRST_RELEASE_COLLECTION_SYMBOL.to_string());
+    data::set_base_token_uri(storage, FIRST_RELEASE_BASE_TOKEN_URI.to_string());
+    data::set_token_uri_suffix(storage, String::new());
+    data::set_metadata_frozen(storage, false);
+    data::set_sale_phases(storage, Vec::new());
+    data::set_merkle_root(storage, None);
+    data::set_pricing(storage, Pricing::Fixed);
+    data::set_auction_purse(storage, storage.create_purse());
+    data::set_auction_mint_count(storage, 0u64);
+    data::set_auction_last_price(storage, U256::zero());
+    data::set_auction_clearing_price(storage, None);
+    data::set_rebate_deadline(storage, None);
+    data::set_treasury_purse(storage, storage.create_purse());
+    data::set_use_treasury(storage, false);
+    data::set_paused(storage, false);
+    data::set_pause_reason(storage, 0u32);
+    data::set_pause_until(storage, None);
+    data::set_receive_entry_point(storage, DEFAULT_RECEIVE_ENTRY_POINT.to_string());
+    data::set_revenue_splits(storage, Vec::new());
+    data::set_remainder_payee(storage, None);
+    // Only packages whose access URef is still held get new versions.
+    data::set_upgrade_key_dropped(storage, false);
+    AuctionBids::init(storage);
+    WhitelistEntries::init(storage);
+    Minted::init(storage);
+    PaymentTokens::init(storage);
+    Roles::init(storage);
+    events::init(storage);
+    for role in Role::ALL {
+        Roles::instance(storage, role).grant(&admin);
+    }
+}
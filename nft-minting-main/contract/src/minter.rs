@@ -1,15 +1,38 @@
-use alloc::vec::Vec;
-use casper_contract::contract_api::system;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_types::{account::AccountHash, Key, URef, U256, U512};
+use casper_types::{Key, URef, U256, U512};
 use contract_utils::{ContractStorage, ContractContext};
 
+use crate::cep18_utils;
 use crate::cep78_utils;
-use crate::data::{self, Whitelist};
+use crate::config::Config;
+use crate::data::{
+    self, AuctionBids, Minted, PaymentTokens, Roles, Whitelist, WhitelistEntries,
+};
 use crate::error::Error;
+use crate::events;
+use crate::migrations;
 use crate::modifiers;
+use crate::phase::Phase;
+use crate::pricing::{PriceTier, Pricing};
+use crate::purse_utils;
+use crate::revenue::{self, RevenueSplit};
+use crate::role::Role;
+use crate::whitelist::WhitelistEntry;
+
+/// The entry point called on contract packages receiving CSPR, with `purse` and `amount` args.
+pub const DEFAULT_RECEIVE_ENTRY_POINT: &str = "receive";
+
+/// Milliseconds buyers have to claim their rebates once a rebate auction ends, 30 days.
+pub const REBATE_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60 * 1000;
 
 pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
+    #[allow(clippy::too_many_arguments)]
     fn init(
         &self, 
         admin: Key, 
@@ -18,110 +41,999 @@ pub trait MINTER<Storage: ContractStorage>: ContractContext<Storage> {
         mint_fee: U256,
         only_whitelist: bool,
         allow_mint: bool,
-        max_mint: u64
+        max_mint: u64,
+        max_supply: u64,
+        collection_name: String,
+        collection_symbol: String,
+        base_token_uri: String,
+        token_uri_suffix: String,
+        locked: bool
     )  {
-        data::set_admin(admin);
-        data::set_fund_manager(fund_manager);
-        data::set_cep78_package_hash(cep78_package_hash);
-        data::set_mint_fee(mint_fee);
-        data::set_mint_count(0u64);
-        data::set_only_whitelist(only_whitelist);
-        data::set_allow_mint(allow_mint);
-        data::set_max_mint(max_mint);
-        Whitelist::init();
+        data::set_admin(self.storage(), Some(admin));
+        data::set_pending_admin(self.storage(), None);
+        data::set_pending_admin_from(self.storage(), None);
+        data::set_fund_manager(self.storage(), fund_manager);
+        data::set_cep78_package_hash(self.storage(), cep78_package_hash);
+        data::set_mint_fee(self.storage(), mint_fee);
+        data::set_mint_count(self.storage(), 0u64);
+        data::set_max_supply(self.storage(), max_supply);
+        data::set_only_whitelist(self.storage(), only_whitelist);
+        data::set_allow_mint(self.storage(), allow_mint);
+        data::set_max_mint(self.storage(), max_mint);
+        data::set_collection_name(self.storage(), collection_name);
+        data::set_collection_symbol(self.storage(), collection_symbol);
+        data::set_base_token_uri(self.storage(), base_token_uri);
+        data::set_token_uri_suffix(self.storage(), token_uri_suffix);
+        data::set_metadata_frozen(self.storage(), false);
+        data::set_sale_phases(self.storage(), Vec::new());
+        data::set_merkle_root(self.storage(), None);
+        data::set_pricing(self.storage(), Pricing::Fixed);
+        data::set_auction_purse(self.storage(), self.storage().create_purse());
+        data::set_auction_mint_count(self.storage(), 0u64);
+        data::set_auction_last_price(self.storage(), U256::zero());
+        data::set_auction_clearing_price(self.storage(), None);
+        data::set_rebate_deadline(self.storage(), None);
+        data::set_treasury_purse(self.storage(), self.storage().create_purse());
+        data::set_use_treasury(self.storage(), false);
+        data::set_paused(self.storage(), false);
+        data::set_pause_reason(self.storage(), 0u32);
+        data::set_pause_until(self.storage(), None);
+        data::set_receive_entry_point(self.storage(), DEFAULT_RECEIVE_ENTRY_POINT.to_string());
+        data::set_revenue_splits(self.storage(), Vec::new());
+        data::set_remainder_payee(self.storage(), None);
+        data::set_schema_version(self.storage(), migrations::SCHEMA_VERSION);
+        // A new contract package always starts at contract version 1.
+        data::set_migrated_version(self.storage(), 1u32);
+        data::set_upgrade_key_dropped(self.storage(), locked);
+        AuctionBids::init(self.storage());
+        Whitelist::init(self.storage());
+        WhitelistEntries::init(self.storage());
+        Minted::init(self.storage());
+        PaymentTokens::init(self.storage());
+        Roles::init(self.storage());
+        events::init(self.storage());
+        for role in Role::ALL {
+            Roles::instance(self.storage(), role).grant(&admin);
+        }
+    }
+
+    /// Runs the migrations between the stored schema version and `migrations::SCHEMA_VERSION`
+    /// for a newly added contract version, at most once per contract version.
+    fn migrate(&self, contract_version: u32) -> Result<(), Error> {
+        if contract_version <= data::get_migrated_version(self.storage()) {
+            return Err(Error::AlreadyMigrated);
+        }
+        let schema_version = data::get_schema_version(self.storage());
+        if schema_version > migrations::SCHEMA_VERSION {
+            return Err(Error::UnsupportedSchemaVersion);
+        }
+        for version in schema_version + 1..=migrations::SCHEMA_VERSION {
+            migrations::migrate_to(self.storage(), version)?;
+            data::set_schema_version(self.storage(), version);
+        }
+        data::set_migrated_version(self.storage(), contract_version);
+        Ok(())
+    }
+
+    /// Records that the installer dropped the package access URef, called by the session code
+    /// right before it removes the URef from its named keys.
+    fn finalize(&self) -> Result<(), Error> {
+        if data::get_upgrade_key_dropped(self.storage()) {
+            return Err(Error::UpgradeKeyDropped);
+        }
+        data::set_upgrade_key_dropped(self.storage(), true);
+        Ok(())
+    }
+
+    /// Whether the installer no longer holds the package access URef, either because the
+    /// package was installed locked or because it was finalized. Only a locked install rules
+    /// out new versions, a finalized package can still be upgraded by a copy of the URef.
+    fn is_upgrade_key_dropped(&self) -> bool {
+        data::get_upgrade_key_dropped(self.storage())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn set_config(
         &self, 
-        admin: Option<Key>,
         fund_manager: Option<Key>, 
         mint_fee: Option<U256>,
         only_whitelist: Option<bool>,
         allow_mint: Option<bool>,
         max_mint: Option<u64>,
+        use_treasury: Option<bool>,
+        receive_entry_point: Option<String>,
     ) -> Result<(), Error> {
-        modifiers::only_admin(self.get_caller())?;
-        if let Some(admin) = admin {
-            data::set_admin(admin);
+        modifiers::when_not_paused(self.storage())?;
+        let caller = self.get_caller();
+        if fund_manager.is_some() || use_treasury.is_some() || receive_entry_point.is_some() {
+            modifiers::only_role(self.storage(), Role::Treasurer, caller)?;
+        }
+        if mint_fee.is_some()
+            || only_whitelist.is_some()
+            || allow_mint.is_some()
+            || max_mint.is_some()
+        {
+            modifiers::only_role(self.storage(), Role::ConfigManager, caller)?;
         }
         if let Some(fund_manager) = fund_manager {
-            data::set_fund_manager(fund_manager);
+            modifiers::valid_recipient(fund_manager)?;
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_FUND_MANAGER,
+                data::get_fund_manager(self.storage()).to_formatted_string(),
+                fund_manager.to_formatted_string(),
+            );
+            data::set_fund_manager(self.storage(), fund_manager);
         }
         if let Some(mint_fee) = mint_fee {
-            data::set_mint_fee(mint_fee);
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_MINT_FEE,
+                data::get_mint_fee(self.storage()),
+                mint_fee,
+            );
+            data::set_mint_fee(self.storage(), mint_fee);
         }
         if let Some(only_whitelist) = only_whitelist {
-            data::set_only_whitelist(only_whitelist);
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_ONLY_WHITELIST,
+                data::get_only_whitelist(self.storage()),
+                only_whitelist,
+            );
+            data::set_only_whitelist(self.storage(), only_whitelist);
         }
         if let Some(allow_mint) = allow_mint {
-            data::set_allow_mint(allow_mint);
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_ALLOW_MINT,
+                data::get_allow_mint(self.storage()),
+                allow_mint,
+            );
+            data::set_allow_mint(self.storage(), allow_mint);
         }
         if let Some(max_mint) = max_mint {
-            data::set_max_mint(max_mint);
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_MAX_MIN,
+                data::get_max_mint(self.storage()),
+                max_mint,
+            );
+            data::set_max_mint(self.storage(), max_mint);
+        }
+        if let Some(use_treasury) = use_treasury {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_USE_TREASURY,
+                data::get_use_treasury(self.storage()),
+                use_treasury,
+            );
+            data::set_use_treasury(self.storage(), use_treasury);
+        }
+        if let Some(receive_entry_point) = receive_entry_point {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_RECEIVE_ENTRY_POINT,
+                data::get_receive_entry_point(self.storage()),
+                receive_entry_point.clone(),
+            );
+            data::set_receive_entry_point(self.storage(), receive_entry_point);
+        }
+        Ok(())
+    }
+
+    fn grant_role(&self, role: Role, account: Key) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Admin, self.get_caller())?;
+        Roles::instance(self.storage(), role).grant(&account);
+        if role == Role::Admin {
+            events::admin_changed(self.storage(), None, Some(account));
+        }
+        Ok(())
+    }
+
+    fn revoke_role(&self, role: Role, account: Key) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Admin, self.get_caller())?;
+        Roles::instance(self.storage(), role).revoke(&account);
+        if role == Role::Admin {
+            forget_admin(self.storage(), account);
+            events::admin_changed(self.storage(), Some(account), None);
+        }
+        Ok(())
+    }
+
+    fn renounce_role(&self, role: Role) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        let caller = self.get_caller();
+        Roles::instance(self.storage(), role).revoke(&caller);
+        if role == Role::Admin {
+            forget_admin(self.storage(), caller);
+            events::admin_changed(self.storage(), Some(caller), None);
+        }
+        Ok(())
+    }
+
+    fn has_role(&self, role: Role, account: Key) -> bool {
+        Roles::instance(self.storage(), role).has(&account)
+    }
+
+    /// Proposes to hand the caller's ADMIN role over to `new_admin`, which only takes effect
+    /// once `new_admin` calls `accept_admin`.
+    fn transfer_admin(&self, new_admin: Key) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        let caller = self.get_caller();
+        modifiers::only_role(self.storage(), Role::Admin, caller)?;
+        data::set_pending_admin(self.storage(), Some(new_admin));
+        data::set_pending_admin_from(self.storage(), Some(caller));
+        Ok(())
+    }
+
+    fn accept_admin(&self) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        let pending_admin = data::get_pending_admin(self.storage()).ok_or(Error::NoPendingAdmin)?;
+        let pending_admin_from = data::get_pending_admin_from(self.storage())
+            .ok_or(Error::NoPendingAdmin)?;
+        if self.get_caller() != pending_admin {
+            return Err(Error::PermissionDenied);
+        }
+        modifiers::only_role(self.storage(), Role::Admin, pending_admin_from)?;
+
+        let admins = Roles::instance(self.storage(), Role::Admin);
+        admins.revoke(&pending_admin_from);
+        admins.grant(&pending_admin);
+        if data::get_admin(self.storage()) == Some(pending_admin_from) {
+            data::set_admin(self.storage(), Some(pending_admin));
+        }
+        data::set_pending_admin(self.storage(), None);
+        data::set_pending_admin_from(self.storage(), None);
+        events::admin_changed(self.storage(), Some(pending_admin_from), Some(pending_admin));
+        Ok(())
+    }
+
+    fn cancel_admin_transfer(&self) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Admin, self.get_caller())?;
+        if data::get_pending_admin(self.storage()).is_none() {
+            return Err(Error::NoPendingAdmin);
+        }
+        data::set_pending_admin(self.storage(), None);
+        data::set_pending_admin_from(self.storage(), None);
+        Ok(())
+    }
+
+    fn get_pending_admin(&self) -> Option<Key> {
+        data::get_pending_admin(self.storage())
+    }
+
+    /// Halts every state changing entry point but `unpause`, until the given block time if any.
+    fn pause(&self, reason: u32, until: Option<u64>) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        let caller = self.get_caller();
+        modifiers::only_role(self.storage(), Role::Guardian, caller)?;
+        data::set_paused(self.storage(), true);
+        data::set_pause_reason(self.storage(), reason);
+        data::set_pause_until(self.storage(), until);
+        events::paused(self.storage(), caller, reason, until);
+        Ok(())
+    }
+
+    fn unpause(&self) -> Result<(), Error> {
+        let caller = self.get_caller();
+        modifiers::only_role(self.storage(), Role::Guardian, caller)?;
+        if !self.is_paused() {
+            return Err(Error::NotPaused);
+        }
+        data::set_paused(self.storage(), false);
+        data::set_pause_until(self.storage(), None);
+        events::unpaused(self.storage(), caller);
+        Ok(())
+    }
+
+    fn is_paused(&self) -> bool {
+        modifiers::when_not_paused(self.storage()).is_err()
+    }
+
+    fn set_metadata(
+        &self,
+        collection_name: Option<String>,
+        collection_symbol: Option<String>,
+        base_token_uri: Option<String>,
+        token_uri_suffix: Option<String>,
+    ) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        modifiers::metadata_not_frozen(self.storage())?;
+        if let Some(collection_name) = collection_name {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_COLLECTION_NAME,
+                data::get_collection_name(self.storage()),
+                collection_name.clone(),
+            );
+            data::set_collection_name(self.storage(), collection_name);
+        }
+        if let Some(collection_symbol) = collection_symbol {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_COLLECTION_SYMBOL,
+                data::get_collection_symbol(self.storage()),
+                collection_symbol.clone(),
+            );
+            data::set_collection_symbol(self.storage(), collection_symbol);
+        }
+        if let Some(base_token_uri) = base_token_uri {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_BASE_TOKEN_URI,
+                data::get_base_token_uri(self.storage()),
+                base_token_uri.clone(),
+            );
+            data::set_base_token_uri(self.storage(), base_token_uri);
+        }
+        if let Some(token_uri_suffix) = token_uri_suffix {
+            events::config_changed(
+                self.storage(),
+                data::NAMED_KEY_TOKEN_URI_SUFFIX,
+                data::get_token_uri_suffix(self.storage()),
+                token_uri_suffix.clone(),
+            );
+            data::set_token_uri_suffix(self.storage(), token_uri_suffix);
+        }
+        Ok(())
+    }
+
+    fn freeze_metadata(&self) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_METADATA_FROZEN,
+            data::get_metadata_frozen(self.storage()),
+            true,
+        );
+        data::set_metadata_frozen(self.storage(), true);
+        Ok(())
+    }
+
+    /// Replaces the sale schedule with one phase per index of the lists.
+    fn set_sale_phases(
+        &self,
+        start_times: Vec<u64>,
+        end_times: Vec<u64>,
+        mint_fees: Vec<U256>,
+        max_mints: Vec<u64>,
+        only_whitelists: Vec<bool>,
+    ) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        modifiers::valid_lengths(&[
+            start_times.len(),
+            end_times.len(),
+            mint_fees.len(),
+            max_mints.len(),
+            only_whitelists.len(),
+        ])?;
+        let phases: Vec<Phase> = (0..start_times.len())
+            .map(|i| Phase {
+                start_time: start_times[i],
+                end_time: end_times[i],
+                mint_fee: mint_fees[i],
+                max_mint: max_mints[i],
+                only_whitelist: only_whitelists[i],
+            })
+            .collect();
+        modifiers::valid_phases(&phases)?;
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_SALE_PHASES,
+            data::get_sale_phases(self.storage()).len(),
+            phases.len(),
+        );
+        data::set_sale_phases(self.storage(), phases);
+        Ok(())
+    }
+
+    /// Returns the phase active at the current block time. Without a sale schedule
+    /// the phase is built from the global mint fee, max mint and whitelist mode.
+    fn get_active_phase(&self) -> Result<Phase, Error> {
+        let phases = data::get_sale_phases(self.storage());
+        if phases.is_empty() {
+            return Ok(Phase {
+                start_time: 0,
+                end_time: u64::MAX,
+                mint_fee: data::get_mint_fee(self.storage()),
+                max_mint: data::get_max_mint(self.storage()),
+                only_whitelist: data::get_only_whitelist(self.storage()),
+            });
+        }
+        let block_time: u64 = self.storage().block_time();
+        phases
+            .into_iter()
+            .find(|phase| phase.is_active(block_time))
+            .ok_or(Error::NoActivePhase)
+    }
+
+    /// Prices NFTs by tiers of supply sold, with one tier per index of the lists.
+    fn set_tiered_pricing(&self, supplies: Vec<u64>, prices: Vec<U256>) -> Result<(), Error> {
+        modifiers::valid_lengths(&[supplies.len(), prices.len()])?;
+        let tiers = supplies
+            .into_iter()
+            .zip(prices)
+            .map(|(supply, price)| PriceTier { supply, price })
+            .collect();
+        self.set_pricing(Pricing::Tiered(tiers))
+    }
+
+    /// Replaces the CSPR pricing strategy. A rebate auction that sold NFTs has to be ended
+    /// first, and a new rebate auction only starts once the previous rebates are claimed.
+    fn set_pricing(&self, pricing: Pricing) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        modifiers::valid_pricing(&pricing)?;
+        modifiers::auction_ended(self.storage())?;
+        if let Pricing::DutchAuction(auction) = &pricing {
+            if auction.rebate {
+                modifiers::no_pending_rebates(self.storage())?;
+                AuctionBids::reset(self.storage());
+                data::set_auction_clearing_price(self.storage(), None);
+                data::set_rebate_deadline(self.storage(), None);
+            }
+        }
+        data::set_auction_mint_count(self.storage(), 0u64);
+        data::set_auction_last_price(self.storage(), U256::zero());
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_PRICING,
+            data::get_pricing(self.storage()).name(),
+            pricing.name(),
+        );
+        data::set_pricing(self.storage(), pricing);
+        Ok(())
+    }
+
+    /// Ends the running Dutch auction and goes back to the phase mint fee. With rebates the
+    /// last price paid becomes the clearing price, the proceeds held above the floor price
+    /// are released to the fund manager and buyers have `REBATE_CLAIM_PERIOD` to claim.
+    fn end_auction(&self) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        let auction = match data::get_pricing(self.storage()) {
+            Pricing::DutchAuction(auction) => auction,
+            _ => return Err(Error::NoAuction),
+        };
+        if auction.rebate {
+            let sold = data::get_auction_mint_count(self.storage());
+            let clearing_price = if sold > 0 {
+                data::get_auction_last_price(self.storage())
+            } else {
+                auction.floor_price
+            };
+            let proceeds = (clearing_price - auction.floor_price) * U256::from(sold);
+            pay_proceeds(self.storage(), data::get_auction_purse(self.storage()), proceeds);
+            data::set_auction_clearing_price(self.storage(), Some(clearing_price));
+            let deadline = self.storage().block_time().saturating_add(REBATE_CLAIM_PERIOD);
+            data::set_rebate_deadline(self.storage(), Some(deadline));
+        }
+        data::set_auction_mint_count(self.storage(), 0u64);
+        data::set_auction_last_price(self.storage(), U256::zero());
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_PRICING,
+            Pricing::DutchAuction(auction).name(),
+            Pricing::Fixed.name(),
+        );
+        data::set_pricing(self.storage(), Pricing::Fixed);
+        Ok(())
+    }
+
+    /// Returns what the account paid above the clearing price of the last rebate auction,
+    /// nothing once the claim deadline has passed.
+    fn get_rebate(&self, account: Key) -> U256 {
+        if modifiers::rebate_claim_open(self.storage()).is_err() {
+            return U256::zero();
+        }
+        match data::get_auction_clearing_price(self.storage()) {
+            Some(clearing_price) => {
+                let (paid, count) = AuctionBids::instance(self.storage()).get(&account);
+                paid.saturating_sub(clearing_price * U256::from(count))
+            }
+            None => U256::zero(),
+        }
+    }
+
+    fn claim_rebate(&self) -> Result<U512, Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::rebate_claim_open(self.storage())?;
+        let caller = self.get_caller();
+        let rebate = self.get_rebate(caller);
+        if rebate.is_zero() {
+            return Err(Error::NoRebate);
+        }
+        AuctionBids::instance(self.storage()).remove(&caller);
+        let amount = to_motes(rebate);
+        purse_utils::transfer(
+            self.storage(),
+            data::get_auction_purse(self.storage()),
+            caller,
+            amount,
+        );
+        Ok(amount)
+    }
+
+    /// Sends the rebates left unclaimed past the claim deadline to the recipient and returns
+    /// the amount, so a new rebate auction can start.
+    fn sweep_rebates(&self, recipient: Key) -> Result<U512, Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Treasurer, self.get_caller())?;
+        modifiers::valid_recipient(recipient)?;
+        if data::get_rebate_deadline(self.storage()).is_none() {
+            return Err(Error::NoRebate);
+        }
+        if modifiers::rebate_claim_open(self.storage()).is_ok() {
+            return Err(Error::RebatesPending);
+        }
+        let auction_purse = data::get_auction_purse(self.storage());
+        let amount = self
+            .storage()
+            .purse_balance(auction_purse)
+            .unwrap_or_revert_with(Error::UableToReadPurse);
+        AuctionBids::reset(self.storage());
+        data::set_auction_clearing_price(self.storage(), None);
+        data::set_rebate_deadline(self.storage(), None);
+        events::withdrawn(self.storage(), recipient, amount);
+        if !amount.is_zero() {
+            purse_utils::transfer(self.storage(), auction_purse, recipient, amount);
         }
+        Ok(amount)
+    }
+
+    /// Replaces the payees sharing the primary sale proceeds. Without payees the whole
+    /// proceeds go to the fund manager.
+    fn set_revenue_splits(
+        &self,
+        payees: Vec<Key>,
+        shares: Vec<u32>,
+        remainder_payee: Option<Key>,
+    ) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Treasurer, self.get_caller())?;
+        modifiers::valid_lengths(&[payees.len(), shares.len()])?;
+        let splits: Vec<RevenueSplit> = payees
+            .into_iter()
+            .zip(shares)
+            .map(|(payee, share)| RevenueSplit { payee, share })
+            .collect();
+        let remainder_payee = if splits.is_empty() {
+            None
+        } else {
+            let remainder_payee = remainder_payee.ok_or(Error::InvalidRevenueSplits)?;
+            modifiers::valid_revenue_splits(&splits, remainder_payee)?;
+            Some(remainder_payee)
+        };
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_REVENUE_SPLITS,
+            data::get_revenue_splits(self.storage()).len(),
+            splits.len(),
+        );
+        data::set_revenue_splits(self.storage(), splits);
+        data::set_remainder_payee(self.storage(), remainder_payee);
+        Ok(())
+    }
+
+    fn get_treasury_balance(&self) -> U512 {
+        self.storage().purse_balance(data::get_treasury_purse(self.storage()))
+            .unwrap_or_revert_with(Error::UableToReadPurse)
+    }
+
+    /// Moves CSPR out of the treasury purse, all of it when no amount is given.
+    fn withdraw(&self, recipient: Key, amount: Option<U512>) -> Result<U512, Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::Treasurer, self.get_caller())?;
+        modifiers::valid_recipient(recipient)?;
+        let treasury_purse = data::get_treasury_purse(self.storage());
+        let amount = amount.unwrap_or_else(|| self.get_treasury_balance());
+        modifiers::enough_native_balance(self.storage(), amount, treasury_purse)?;
+        purse_utils::transfer(self.storage(), treasury_purse, recipient, amount);
+        events::withdrawn(self.storage(), recipient, amount);
+        Ok(amount)
+    }
+
+    fn set_merkle_root(&self, merkle_root: Option<[u8; 32]>) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::WhitelistManager, self.get_caller())?;
+        events::config_changed(
+            self.storage(),
+            data::NAMED_KEY_MERKLE_ROOT,
+            data::get_merkle_root(self.storage()).map(hex::encode).unwrap_or_default(),
+            merkle_root.map(hex::encode).unwrap_or_default(),
+        );
+        data::set_merkle_root(self.storage(), merkle_root);
         Ok(())
     }
 
+    /// Checks that the NFT owner may mint during the phase and returns its mint limit. A Merkle
+    /// proof takes precedence over the whitelist dictionaries: with a proof the owner is checked
+    /// against the Merkle allowlist, and an allowance bound to its leaf replaces the phase max
+    /// mint even if the owner also has a whitelist entry. Without a proof the owner is checked
+    /// against the whitelist, and an unexpired whitelist entry allowance replaces the max mint.
+    fn allowed_mint(
+        &self,
+        phase: &Phase,
+        nft_owner: Key,
+        merkle_proof: Option<Vec<[u8; 32]>>,
+        merkle_allowance: Option<u64>,
+    ) -> Result<u64, Error> {
+        match merkle_proof {
+            Some(merkle_proof) => {
+                modifiers::valid_merkle_proof(
+                    self.storage(),
+                    nft_owner,
+                    merkle_allowance,
+                    &merkle_proof,
+                )?;
+                Ok(merkle_allowance.unwrap_or(phase.max_mint))
+            }
+            None => {
+                modifiers::valid_account(self.storage(), nft_owner, phase.only_whitelist)?;
+                Ok(modifiers::mint_limit(self.storage(), nft_owner, phase.max_mint))
+            }
+        }
+    }
+
     fn set_whitelist(&self, accounts: Vec<Key>, values: Vec<bool>) -> Result<(), Error> {
-        modifiers::only_admin(self.get_caller())?;
-        let whitelist = Whitelist::instance();
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::WhitelistManager, self.get_caller())?;
+        modifiers::valid_lengths(&[accounts.len(), values.len()])?;
+        let whitelist = Whitelist::instance(self.storage());
         for (key, &value) in accounts.iter().zip(values.iter()) {
             whitelist.set(key, value);
+            events::whitelist_updated(self.storage(), *key, value);
         }
         Ok(())
     }
 
+    /// Clears both the plain whitelist and the whitelist entries before whitelisting the accounts.
     fn reset_whitelist(&self, accounts: Vec<Key>, values: Vec<bool>) -> Result<(), Error> {
-        modifiers::only_admin(self.get_caller())?;
-        Whitelist::reset();
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::WhitelistManager, self.get_caller())?;
+        modifiers::valid_lengths(&[accounts.len(), values.len()])?;
+        Whitelist::reset(self.storage());
+        WhitelistEntries::reset(self.storage());
         self.set_whitelist(accounts, values)?;
         Ok(())
     }
 
-    fn get_mint_cost(&self, count: u64) -> U256 {
-        data::get_mint_fee()
-         .checked_mul(U256::try_from(count).unwrap())
-         .unwrap()
-     }
+    /// Whitelists the accounts with the terms at the same index of the other lists.
+    fn set_whitelist_entries(
+        &self,
+        accounts: Vec<Key>,
+        allowances: Vec<Option<u64>>,
+        prices: Vec<Option<U256>>,
+        expiries: Vec<Option<u64>>,
+    ) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::WhitelistManager, self.get_caller())?;
+        modifiers::valid_lengths(&[
+            accounts.len(),
+            allowances.len(),
+            prices.len(),
+            expiries.len(),
+        ])?;
+        let whitelist_entries = WhitelistEntries::instance(self.storage());
+        for (i, key) in accounts.into_iter().enumerate() {
+            let entry = WhitelistEntry {
+                allowance: allowances[i],
+                price: prices[i],
+                expiry: expiries[i],
+            };
+            whitelist_entries.set(&key, entry);
+            events::whitelist_updated(self.storage(), key, true);
+        }
+        Ok(())
+    }
+
+    fn set_payment_token(&self, payment_token: Key, mint_fee: U256) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        let payment_tokens = PaymentTokens::instance(self.storage());
+        events::config_changed(
+            self.storage(),
+            &payment_token_field(&payment_token),
+            payment_tokens.get(&payment_token).map(|fee| fee.to_string()).unwrap_or_default(),
+            mint_fee.to_string(),
+        );
+        payment_tokens.set(&payment_token, mint_fee);
+        Ok(())
+    }
+
+    fn remove_payment_token(&self, payment_token: Key) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::ConfigManager, self.get_caller())?;
+        let payment_tokens = PaymentTokens::instance(self.storage());
+        events::config_changed(
+            self.storage(),
+            &payment_token_field(&payment_token),
+            payment_tokens.get(&payment_token).map(|fee| fee.to_string()).unwrap_or_default(),
+            String::new(),
+        );
+        payment_tokens.remove(&payment_token);
+        Ok(())
+    }
+
+    /// Returns the CSPR cost of minting NFTs, for the account when given so that the price
+    /// of its whitelist entry applies.
+    fn get_mint_cost(&self, count: u64, account: Option<Key>) -> Result<U256, Error> {
+        let phase = self.get_active_phase()?;
+        Ok(match account.and_then(|account| price_override(self.storage(), &account)) {
+            Some(price) => price.checked_mul(U256::from(count)).unwrap(),
+            None => mint_cost(self.storage(), &data::get_pricing(self.storage()), &phase, count),
+        })
+    }
+
+    fn get_token_mint_cost(&self, payment_token: Key, count: u64) -> Result<U256, Error> {
+        let mint_fee = PaymentTokens::instance(self.storage())
+            .get(&payment_token)
+            .ok_or(Error::PaymentTokenNotAccepted)?;
+        Ok(mint_fee.checked_mul(U256::from(count)).unwrap())
+    }
 
-    fn mint_nft(&self,  nft_owner: Key, count: u64) -> Result<(), Error> {
-        let mut mint_count = data::get_mint_count();
-        for _ in 0..count {
-            cep78_utils::mint(nft_owner, mint_count);
-            mint_count += 1;
+    fn get_config(&self) -> Config {
+        Config {
+            admin: data::get_admin(self.storage()),
+            fund_manager: data::get_fund_manager(self.storage()),
+            cep78_package_hash: data::get_cep78_package_hash(self.storage()),
+            mint_fee: data::get_mint_fee(self.storage()),
+            only_whitelist: data::get_only_whitelist(self.storage()),
+            allow_mint: data::get_allow_mint(self.storage()),
+            paused: self.is_paused(),
+            use_treasury: data::get_use_treasury(self.storage()),
+            metadata_frozen: data::get_metadata_frozen(self.storage()),
+            upgrade_key_dropped: data::get_upgrade_key_dropped(self.storage()),
+            max_mint: data::get_max_mint(self.storage()),
+            max_supply: data::get_max_supply(self.storage()),
+            mint_count: data::get_mint_count(self.storage()),
         }
-        data::set_mint_count(mint_count);
+    }
+
+    /// Returns `true` if the account is in the whitelist or has an unexpired whitelist entry.
+    fn is_whitelisted(&self, account: Key) -> bool {
+        modifiers::valid_account(self.storage(), account, true).is_ok()
+    }
+
+    fn minted_by(&self, account: Key) -> u64 {
+        Minted::instance(self.storage()).get(&account)
+    }
+
+    /// Returns how many more NFTs the account can mint in the active sale phase, bounded by
+    /// the remaining supply. Merkle allowlist claims are not known on chain, so an account
+    /// only allowed through a Merkle proof gets zero.
+    fn remaining_for(&self, account: Key) -> u64 {
+        let phase = match self.get_active_phase() {
+            Ok(phase) => phase,
+            Err(_) => return 0,
+        };
+        if modifiers::valid_account(self.storage(), account, phase.only_whitelist).is_err() {
+            return 0;
+        }
+        modifiers::mint_limit(self.storage(), account, phase.max_mint)
+            .saturating_sub(self.minted_by(account))
+            .min(self.get_remaining_supply())
+    }
+
+    fn get_remaining_supply(&self) -> u64 {
+        data::get_max_supply(self.storage()).saturating_sub(data::get_mint_count(self.storage()))
+    }
+
+    fn mint_nft(
+        &self,
+        nft_owner: Key,
+        count: u64,
+        paid: U256,
+        payment_token: Option<Key>,
+    ) -> Result<(), Error> {
+        modifiers::within_supply(self.storage(), count)?;
+        let first_index = mint_tokens(self.storage(), nft_owner, count)?;
+        events::minted(self.storage(), nft_owner, count, first_index, paid, payment_token);
         Ok(())
     }
 
+    /// Freely mints NFTs to many owners at once and returns the total minted. The whole batch
+    /// has to fit in the remaining supply, and a single `Airdropped` event sums it up.
+    fn airdrop(&self, recipients: Vec<Key>, counts: Vec<u64>) -> Result<u64, Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::FreeMinter, self.get_caller())?;
+        modifiers::valid_lengths(&[recipients.len(), counts.len()])?;
+        if recipients.is_empty() {
+            return Err(Error::WrongArguments);
+        }
+        let drops: Vec<(Key, u64)> = recipients.into_iter().zip(counts).collect();
+        let total = drops
+            .iter()
+            .try_fold(0u64, |total, (_, count)| total.checked_add(*count))
+            .ok_or(Error::SupplyExceeded)?;
+        modifiers::within_supply(self.storage(), total)?;
+        let first_index = data::get_mint_count(self.storage());
+        for (nft_owner, count) in &drops {
+            mint_tokens(self.storage(), *nft_owner, *count)?;
+        }
+        events::airdropped(self.storage(), drops.len() as u64, total, first_index);
+        Ok(total)
+    }
+
     fn free_mint(&self,  nft_owner: Key, count: u64) -> Result<(), Error> {
-        modifiers::only_admin(self.get_caller())?;
-        self.mint_nft(nft_owner, count)?;
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::only_role(self.storage(), Role::FreeMinter, self.get_caller())?;
+        self.mint_nft(nft_owner, count, U256::zero(), None)?;
         Ok(())
     }
 
-    fn native_mint(&self, nft_owner: Key, count: u64, source_purse: URef) -> Result<(), Error> {
-        modifiers::mint_allowed()?;
-        modifiers::valid_account(nft_owner)?;
-        modifiers::limited_mint(nft_owner, count)?;
+    fn native_mint(
+        &self,
+        nft_owner: Key,
+        count: u64,
+        source_purse: URef,
+        merkle_proof: Option<Vec<[u8; 32]>>,
+        merkle_allowance: Option<u64>,
+    ) -> Result<U512, Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::mint_allowed(self.storage())?;
+        let phase = self.get_active_phase()?;
+        let max_mint = self.allowed_mint(&phase, nft_owner, merkle_proof, merkle_allowance)?;
+        modifiers::limited_mint(self.storage(), nft_owner, count, max_mint)?;
 
-        let amount = U512::try_from(self.get_mint_cost(count).as_u128()).unwrap();
-        modifiers::enough_native_balance(amount, source_purse)?;
+        let pricing = data::get_pricing(self.storage());
+        let price_override = price_override(self.storage(), &nft_owner);
+        let cost = self.get_mint_cost(count, Some(nft_owner))?;
+        let amount = to_motes(cost);
+        modifiers::enough_native_balance(self.storage(), amount, source_purse)?;
 
-        system::transfer_from_purse_to_account(
-            source_purse,
-            get_fund_manager(), 
-            amount,
-            None
-        ).unwrap_or_revert();
+        let auction = match (&pricing, price_override) {
+            // Whitelist entry prices stay out of the auction and its clearing price.
+            (Pricing::DutchAuction(auction), None) => Some(auction),
+            _ => None,
+        };
+        if let Some(auction) = auction {
+            if auction.rebate {
+                AuctionBids::instance(self.storage()).add(&self.get_caller(), cost, count);
+            }
+            data::set_auction_last_price(
+                self.storage(),
+                auction.price_at(self.storage().block_time()),
+            );
+            data::set_auction_mint_count(
+                self.storage(),
+                data::get_auction_mint_count(self.storage()) + count,
+            );
+        }
+        self.mint_nft(nft_owner, count, cost, None)?;
+
+        // Payees behind a contract package are called back, so they are paid only once every
+        // state change of the mint is written.
+        match auction {
+            Some(auction) if auction.rebate => {
+                // Only the floor price is final, the rest waits in the auction purse until
+                // the clearing price is known.
+                let floor_cost = auction.floor_price * U256::from(count);
+                pay_proceeds(self.storage(), source_purse, floor_cost);
+                if cost > floor_cost {
+                    self.storage().transfer_from_purse_to_purse(
+                        source_purse,
+                        data::get_auction_purse(self.storage()),
+                        to_motes(cost - floor_cost)
+                    ).unwrap_or_revert();
+                }
+            }
+            _ => pay_proceeds(self.storage(), source_purse, cost),
+        }
+        Ok(amount)
+    }
 
-        self.mint_nft(nft_owner, count)?;
+    fn token_mint(
+        &self,
+        nft_owner: Key,
+        count: u64,
+        payment_token: Key,
+        merkle_proof: Option<Vec<[u8; 32]>>,
+        merkle_allowance: Option<u64>,
+    ) -> Result<(), Error> {
+        modifiers::when_not_paused(self.storage())?;
+        modifiers::mint_allowed(self.storage())?;
+        let phase = self.get_active_phase()?;
+        let max_mint = self.allowed_mint(&phase, nft_owner, merkle_proof, merkle_allowance)?;
+        modifiers::limited_mint(self.storage(), nft_owner, count, max_mint)?;
+
+        let amount = self.get_token_mint_cost(payment_token, count)?;
+        self.mint_nft(nft_owner, count, amount, Some(payment_token))?;
+
+        // The token is paid last, as its contract may call back into the minter.
+        for (payee, part) in payouts(self.storage(), amount) {
+            if !part.is_zero() {
+                cep18_utils::transfer_from(
+                    self.storage(),
+                    payment_token,
+                    self.get_caller(),
+                    payee,
+                    part,
+                );
+            }
+        }
         Ok(())
     }
 }
 
-fn get_fund_manager() -> AccountHash {
-    data::get_fund_manager()
-    .into_account()
-    .unwrap_or_revert_with(Error::InvalidAccountHash)
+/// Mints NFTs to the owner and returns the index of the first one. The counts are updated
+/// before calling the CEP-78 collection.
+fn mint_tokens(storage: &impl ContractStorage, nft_owner: Key, count: u64) -> Result<u64, Error> {
+    let first_index = data::get_mint_count(storage);
+    data::set_mint_count(storage, first_index + count);
+    Minted::instance(storage).add(&nft_owner, count)?;
+    for token_index in first_index..first_index + count {
+        cep78_utils::mint(storage, nft_owner, token_index);
+    }
+    Ok(first_index)
+}
+
+/// Clears the tracked admin when it loses the ADMIN role.
+fn forget_admin(storage: &impl ContractStorage, account: Key) {
+    if data::get_admin(storage) == Some(account) {
+        data::set_admin(storage, None);
+    }
+}
+
+/// Returns the CSPR price of one NFT set by the unexpired whitelist entry of the account.
+fn price_override(storage: &impl ContractStorage, account: &Key) -> Option<U256> {
+    WhitelistEntries::instance(storage)
+        .get_valid(account, storage.block_time())
+        .and_then(|entry| entry.price)
+}
+
+/// Returns the CSPR cost of minting the next `count` NFTs under the pricing strategy.
+fn mint_cost(storage: &impl ContractStorage, pricing: &Pricing, phase: &Phase, count: u64) -> U256 {
+    pricing.cost(
+        phase.mint_fee,
+        storage.block_time(),
+        data::get_mint_count(storage),
+        count,
+    )
+}
+
+fn to_motes(amount: U256) -> U512 {
+    U512::try_from(amount.as_u128()).unwrap()
+}
+
+/// Returns the part of the proceeds owed to each payee, all to the fund manager unless
+/// revenue splits are set.
+fn payouts(storage: &impl ContractStorage, amount: U256) -> Vec<(Key, U256)> {
+    match data::get_remainder_payee(storage) {
+        Some(remainder_payee) => {
+            revenue::split(amount, &data::get_revenue_splits(storage), remainder_payee)
+        }
+        None => vec![(data::get_fund_manager(storage), amount)],
+    }
+}
+
+/// Pays the proceeds into the treasury purse when enabled, otherwise to the payees.
+fn pay_proceeds(storage: &impl ContractStorage, source_purse: URef, amount: U256) {
+    if data::get_use_treasury(storage) {
+        if !amount.is_zero() {
+            storage.transfer_from_purse_to_purse(
+                source_purse,
+                data::get_treasury_purse(storage),
+                to_motes(amount)
+            ).unwrap_or_revert();
+        }
+        return;
+    }
+    for (payee, part) in payouts(storage, amount) {
+        if part.is_zero() {
+            continue;
+        }
+        purse_utils::transfer(storage, source_purse, payee, to_motes(part));
+    }
+}
+
+fn payment_token_field(payment_token: &Key) -> String {
+    format!("{}:{}", data::NAMED_KEY_PAYMENT_TOKENS_DICT, payment_token.to_formatted_string())
 }
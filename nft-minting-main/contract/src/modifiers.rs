@@ -1,90 +1,381 @@
 use casper_types::{Key, URef, U512};
-use casper_contract::{contract_api::system, unwrap_or_revert::UnwrapOrRevert};
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use contract_utils::ContractStorage;
 
-use crate::cep78_utils;
-use crate::data::{self, Whitelist};
+use crate::data::{self, Minted, Roles, Whitelist, WhitelistEntries};
 use crate::error::Error;
+use crate::merkle;
+use crate::phase::Phase;
+use crate::pricing::Pricing;
+use crate::revenue::{RevenueSplit, TOTAL_SHARES};
+use crate::role::Role;
 
-/// Checks if the caller is admin.
+/// Checks if the caller has the role.
 ///
 /// # Arguments
 ///
+/// * `storage`: The contract storage.
+/// * `role`: The role required by the entry point.
 /// * `caller`: The key of caller.
 ///
 /// # Returns
 ///
-/// A `Result`. If the caller is admin, the result will be `Ok(())`.
-pub fn only_admin(caller: Key) -> Result<(), Error> {
-    if caller != data::get_admin() {
+/// A `Result`. If the caller has the role, the result will be `Ok(())`.
+pub fn only_role(storage: &impl ContractStorage, role: Role, caller: Key) -> Result<(), Error> {
+    if !Roles::instance(storage, role).has(&caller) {
         return Err(Error::PermissionDenied);
     }
     Ok(())
 }
 
+/// Checks if the contract is not paused. A pause with an end time lifts by itself once the
+/// block time reaches it.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
+/// # Returns
+///
+/// A `Result`. If the contract is not paused, the result will be `Ok(())`.
+pub fn when_not_paused(storage: &impl ContractStorage) -> Result<(), Error> {
+    if data::get_paused(storage) {
+        let block_time = storage.block_time();
+        if data::get_pause_until(storage).map_or(true, |until| block_time < until) {
+            return Err(Error::Paused);
+        }
+    }
+    Ok(())
+}
+
 /// Checks if the minting is allowed.
 ///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
 /// # Returns
 ///
 /// A `Result`. If the minting is allowed, the result will be `Ok(())`.
-pub fn mint_allowed() -> Result<(), Error> {
-    if !data::get_allow_mint() {
+pub fn mint_allowed(storage: &impl ContractStorage) -> Result<(), Error> {
+    if !data::get_allow_mint(storage) {
         return Err(Error::MintNotAllowed);
     }
     Ok(())
 }
 
-/// Checks if the NFT owner max NFT holding exceeds.
+/// Checks if the collection metadata can still be updated.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
+/// # Returns
+///
+/// A `Result`. If the metadata is not frozen, the result will be `Ok(())`.
+pub fn metadata_not_frozen(storage: &impl ContractStorage) -> Result<(), Error> {
+    if data::get_metadata_frozen(storage) {
+        return Err(Error::MetadataFrozen);
+    }
+    Ok(())
+}
+
+/// Checks if minting more NFTs stays within the collection supply.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+/// * `count`: The number of NFTs to mint.
+///
+/// # Returns
+///
+/// A `Result`. If the max supply is not exceeded, the result will be `Ok(())`.
+pub fn within_supply(storage: &impl ContractStorage, count: u64) -> Result<(), Error> {
+    let total_minted = data::get_mint_count(storage)
+        .checked_add(count)
+        .ok_or(Error::SupplyExceeded)?;
+    if total_minted > data::get_max_supply(storage) {
+        return Err(Error::SupplyExceeded);
+    }
+    Ok(())
+}
+
+/// Returns the maximum number of NFTs the owner is allowed to mint, which is the allowance
+/// of its unexpired whitelist entry if any.
 ///
 /// # Arguments
 ///
+/// * `storage`: The contract storage.
+/// * `nft_owner`: The key of NFT owner.
+/// * `max_mint`: The maximum number of NFTs owners are allowed to mint otherwise.
+pub fn mint_limit(storage: &impl ContractStorage, nft_owner: Key, max_mint: u64) -> u64 {
+    WhitelistEntries::instance(storage)
+        .get_valid(&nft_owner, storage.block_time())
+        .and_then(|entry| entry.allowance)
+        .unwrap_or(max_mint)
+}
+
+/// Checks if the NFT owner max NFT mints exceeds. NFTs minted to the owner are counted
+/// even if they were transferred away afterwards.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
 /// * `nft_owner`: The key of NFT owner.
 /// * `count`: The number of NFTs owner want to mint.
+/// * `max_mint`: The maximum number of NFTs owner is allowed to mint, from `mint_limit` or
+///   a Merkle leaf allowance.
 ///
 /// # Returns
 ///
-/// A `Result`. If the limit of NFT holding not exceed, the result will be `Ok(())`.
-pub fn limited_mint(nft_owner: Key, count: u64) -> Result<(), Error> {
-    let mut owner_holding = cep78_utils::balance_of(nft_owner);
-    owner_holding += count;
-    if owner_holding > data::get_max_mint() {
+/// A `Result`. If the limit of NFT mints not exceed, the result will be `Ok(())`.
+pub fn limited_mint(
+    storage: &impl ContractStorage,
+    nft_owner: Key,
+    count: u64,
+    max_mint: u64,
+) -> Result<(), Error> {
+    let owner_minted = Minted::instance(storage)
+        .get(&nft_owner)
+        .checked_add(count)
+        .ok_or(Error::MintLimitExceed)?;
+    if owner_minted > max_mint {
         return Err(Error::MintLimitExceed);
     }
     Ok(())
 }
 
-/// Checks if the account is whitelisted for minting.
+/// Checks if the account is whitelisted for minting, either in the plain whitelist or
+/// through an unexpired whitelist entry.
 ///
 /// # Arguments
 ///
+/// * `storage`: The contract storage.
 /// * `account`: The key of NFT owner.
+/// * `only_whitelist`: Whether only whitelisted accounts can mint.
 ///
 /// # Returns
 ///
 /// A `Result`. If the account is whitelisted, the result will be `Ok(())`.
-pub fn valid_account(account: Key) -> Result<(), Error> {
-    if data::get_only_whitelist() {
-        let whitelist = Whitelist::instance();
-        if !whitelist.get(&account) {
+pub fn valid_account(
+    storage: &impl ContractStorage,
+    account: Key,
+    only_whitelist: bool,
+) -> Result<(), Error> {
+    if only_whitelist {
+        let whitelist = Whitelist::instance(storage);
+        let entry = WhitelistEntries::instance(storage)
+            .get_valid(&account, storage.block_time());
+        if !whitelist.get(&account) && entry.is_none() {
             return Err(Error::NotWhitelisted);
         }
     }
     Ok(())
 }
 
+/// Checks if the account is in the Merkle allowlist.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+/// * `account`: The key of NFT owner.
+/// * `allowance`: The mint allowance bound to the account leaf, if any.
+/// * `proof`: The sibling hashes from the account leaf up to the root.
+///
+/// # Returns
+///
+/// A `Result`. If the proof leads to the stored Merkle root, the result will be `Ok(())`.
+pub fn valid_merkle_proof(
+    storage: &impl ContractStorage,
+    account: Key,
+    allowance: Option<u64>,
+    proof: &[[u8; 32]],
+) -> Result<(), Error> {
+    let merkle_root = data::get_merkle_root(storage).ok_or(Error::InvalidMerkleProof)?;
+    if !merkle::verify(storage, merkle_root, merkle::leaf(storage, &account, allowance), proof) {
+        return Err(Error::InvalidMerkleProof);
+    }
+    Ok(())
+}
+
 /// Checks if the purse hold enough CSPR tokens.
 ///
 /// # Arguments
 ///
+/// * `storage`: The contract storage.
 /// * `amount`: The required amount that the purse should have.
 /// * `purse`: The purse URef.
 ///
 /// # Returns
 ///
 /// A `Result`. If the purse have required balance, the result will be `Ok(())`.
-pub fn enough_native_balance(amount: U512, purse: URef) -> Result<(), Error> {
-    let balance = system::get_purse_balance(purse).unwrap_or_revert_with(Error::UableToReadPurse);
+pub fn enough_native_balance(
+    storage: &impl ContractStorage,
+    amount: U512,
+    purse: URef,
+) -> Result<(), Error> {
+    let balance = storage.purse_balance(purse).unwrap_or_revert_with(Error::UableToReadPurse);
     if amount > balance {
         return Err(Error::NotEnoughBalance);
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Checks if parallel argument lists describe the same number of items.
+///
+/// # Arguments
+///
+/// * `lengths`: The length of each list.
+///
+/// # Returns
+///
+/// A `Result`. If every list has the same length, the result will be `Ok(())`.
+pub fn valid_lengths(lengths: &[usize]) -> Result<(), Error> {
+    if lengths.windows(2).any(|pair| pair[0] != pair[1]) {
+        return Err(Error::WrongArguments);
+    }
+    Ok(())
+}
+
+/// Checks if the sale phases are well formed and ordered in time.
+///
+/// # Arguments
+///
+/// * `phases`: The sale phases.
+///
+/// # Returns
+///
+/// A `Result`. If every phase ends after it starts and no phases overlap, the result will be `Ok(())`.
+pub fn valid_phases(phases: &[Phase]) -> Result<(), Error> {
+    if phases.iter().any(|phase| phase.start_time >= phase.end_time) {
+        return Err(Error::InvalidPhase);
+    }
+    if phases.windows(2).any(|pair| pair[0].end_time > pair[1].start_time) {
+        return Err(Error::InvalidPhase);
+    }
+    Ok(())
+}
+
+/// Checks if the pricing strategy is well formed.
+///
+/// # Arguments
+///
+/// * `pricing`: The pricing strategy.
+///
+/// # Returns
+///
+/// A `Result`. If a Dutch auction starts above its floor and drops at a non-zero interval, and
+/// price tiers are not empty and ordered by supply, the result will be `Ok(())`.
+pub fn valid_pricing(pricing: &Pricing) -> Result<(), Error> {
+    match pricing {
+        Pricing::DutchAuction(auction) => {
+            if auction.start_price < auction.floor_price || auction.step_interval == 0 {
+                return Err(Error::InvalidPricing);
+            }
+        }
+        Pricing::Tiered(tiers) => {
+            if tiers.is_empty() || tiers.windows(2).any(|pair| pair[0].supply >= pair[1].supply) {
+                return Err(Error::InvalidPricing);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Checks if the key can receive CSPR.
+///
+/// # Arguments
+///
+/// * `recipient`: The key receiving the CSPR.
+///
+/// # Returns
+///
+/// A `Result`. If the key is an account hash or a contract package hash, the result will be `Ok(())`.
+pub fn valid_recipient(recipient: Key) -> Result<(), Error> {
+    match recipient {
+        Key::Account(_) | Key::Hash(_) => Ok(()),
+        _ => Err(Error::InvalidRecipient),
+    }
+}
+
+/// Checks if the revenue splits share the whole proceeds.
+///
+/// # Arguments
+///
+/// * `splits`: The payees and their shares in basis points.
+/// * `remainder_payee`: The payee receiving the rounding dust.
+///
+/// # Returns
+///
+/// A `Result`. If the shares sum to 10,000 and the remainder payee is one of the payees,
+/// the result will be `Ok(())`.
+pub fn valid_revenue_splits(splits: &[RevenueSplit], remainder_payee: Key) -> Result<(), Error> {
+    let total_shares = splits
+        .iter()
+        .try_fold(0u32, |total, split| total.checked_add(split.share))
+        .ok_or(Error::InvalidRevenueSplits)?;
+    if total_shares != TOTAL_SHARES {
+        return Err(Error::InvalidRevenueSplits);
+    }
+    if !splits.iter().any(|split| split.payee == remainder_payee) {
+        return Err(Error::InvalidRevenueSplits);
+    }
+    for split in splits {
+        valid_recipient(split.payee)?;
+    }
+    Ok(())
+}
+
+/// Checks if the running Dutch auction can be replaced without losing buyer rebates.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
+/// # Returns
+///
+/// A `Result`. If no rebate auction has sold NFTs since it started, the result will be `Ok(())`.
+pub fn auction_ended(storage: &impl ContractStorage) -> Result<(), Error> {
+    if let Pricing::DutchAuction(auction) = data::get_pricing(storage) {
+        if auction.rebate && data::get_auction_mint_count(storage) > 0 {
+            return Err(Error::AuctionNotEnded);
+        }
+    }
+    Ok(())
+}
+
+/// Checks if buyers can still claim the rebates of the last rebate auction.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
+/// # Returns
+///
+/// A `Result`. If no claim deadline is set or the block time has not reached it, the result
+/// will be `Ok(())`.
+pub fn rebate_claim_open(storage: &impl ContractStorage) -> Result<(), Error> {
+    match data::get_rebate_deadline(storage) {
+        Some(deadline) if storage.block_time() >= deadline => Err(Error::RebateClaimExpired),
+        _ => Ok(()),
+    }
+}
+
+/// Checks if every rebate of the previous Dutch auction has been claimed or swept.
+///
+/// # Arguments
+///
+/// * `storage`: The contract storage.
+///
+/// # Returns
+///
+/// A `Result`. If the auction purse is empty, the result will be `Ok(())`.
+pub fn no_pending_rebates(storage: &impl ContractStorage) -> Result<(), Error> {
+    let balance = storage.purse_balance(data::get_auction_purse(storage))
+        .unwrap_or_revert_with(Error::UableToReadPurse);
+    if !balance.is_zero() {
+        return Err(Error::RebatesPending);
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+/// A sale phase with its own time window and minting terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    /// Block time in milliseconds from which the phase is active.
+    pub start_time: u64,
+    /// Block time in milliseconds from which the phase is no longer active.
+    pub end_time: u64,
+    /// The CSPR fee required to mint one NFT.
+    pub mint_fee: U256,
+    /// The maximum number of NFTs per account is allowed to mint. It caps every NFT minted to
+    /// the account so far, in this phase and earlier ones, so a later phase has to allow more
+    /// than an earlier one for its buyers to mint again.
+    pub max_mint: u64,
+    /// Whether only whitelisted accounts can mint.
+    pub only_whitelist: bool,
+}
+
+impl Phase {
+    /// Returns `true` if the phase is active at the given block time.
+    pub fn is_active(&self, block_time: u64) -> bool {
+        self.start_time <= block_time && block_time < self.end_time
+    }
+}
+
+impl CLTyped for Phase {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Phase {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.start_time.to_bytes()?);
+        result.extend(self.end_time.to_bytes()?);
+        result.extend(self.mint_fee.to_bytes()?);
+        result.extend(self.max_mint.to_bytes()?);
+        result.extend(self.only_whitelist.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.start_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.mint_fee.serialized_length()
+            + self.max_mint.serialized_length()
+            + self.only_whitelist.serialized_length()
+    }
+}
+
+impl FromBytes for Phase {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (start_time, bytes) = u64::from_bytes(bytes)?;
+        let (end_time, bytes) = u64::from_bytes(bytes)?;
+        let (mint_fee, bytes) = U256::from_bytes(bytes)?;
+        let (max_mint, bytes) = u64::from_bytes(bytes)?;
+        let (only_whitelist, bytes) = bool::from_bytes(bytes)?;
+        Ok((
+            Phase {
+                start_time,
+                end_time,
+                mint_fee,
+                max_mint,
+                only_whitelist,
+            },
+            bytes,
+        ))
+    }
+}
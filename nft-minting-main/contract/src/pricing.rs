@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, U256,
+};
+
+const PRICING_FIXED_TAG: u8 = 0;
+const PRICING_DUTCH_AUCTION_TAG: u8 = 1;
+const PRICING_TIERED_TAG: u8 = 2;
+const PRICING_LINEAR_TAG: u8 = 3;
+
+/// How the CSPR price of an NFT is computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pricing {
+    /// The mint fee of the active sale phase.
+    Fixed,
+    /// A price descending with block time.
+    DutchAuction(DutchAuction),
+    /// A price rising by tiers of NFTs sold, ordered by supply.
+    Tiered(Vec<PriceTier>),
+    /// A price rising by `price_increment` with every NFT sold.
+    Linear {
+        base_price: U256,
+        price_increment: U256,
+    },
+}
+
+/// A descending price that drops by `price_step` every `step_interval` from `start_time`
+/// until it reaches `floor_price`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DutchAuction {
+    /// Block time in milliseconds from which the price starts dropping.
+    pub start_time: u64,
+    pub start_price: U256,
+    pub floor_price: U256,
+    pub price_step: U256,
+    /// Milliseconds between two price drops.
+    pub step_interval: u64,
+    /// Whether buyers can claim back what they paid above the clearing price.
+    pub rebate: bool,
+}
+
+/// The price of NFTs minted while the collection supply is below `supply`.
+/// NFTs minted past the last tier keep its price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceTier {
+    pub supply: u64,
+    pub price: U256,
+}
+
+impl Pricing {
+    /// Returns the name of the pricing strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pricing::Fixed => "fixed",
+            Pricing::DutchAuction(_) => "dutch_auction",
+            Pricing::Tiered(_) => "tiered",
+            Pricing::Linear { .. } => "linear",
+        }
+    }
+
+    /// Returns the CSPR cost of minting `count` NFTs after `sold` NFTs were minted. Every NFT
+    /// of the batch is priced on its own, so a batch crossing a tier pays each tier price.
+    pub fn cost(&self, mint_fee: U256, block_time: u64, sold: u64, count: u64) -> U256 {
+        match self {
+            Pricing::Fixed => mint_fee.checked_mul(U256::from(count)).unwrap(),
+            Pricing::DutchAuction(auction) => auction
+                .price_at(block_time)
+                .checked_mul(U256::from(count))
+                .unwrap(),
+            Pricing::Tiered(tiers) => {
+                let end = sold.saturating_add(count);
+                let mut index = sold;
+                let mut cost = U256::zero();
+                for tier in tiers {
+                    if index >= end {
+                        break;
+                    }
+                    if index < tier.supply {
+                        let units = end.min(tier.supply) - index;
+                        cost += tier.price.checked_mul(U256::from(units)).unwrap();
+                        index += units;
+                    }
+                }
+                if let Some(last_tier) = tiers.last() {
+                    cost += last_tier.price.checked_mul(U256::from(end - index)).unwrap();
+                }
+                cost
+            }
+            Pricing::Linear {
+                base_price,
+                price_increment,
+            } => {
+                // Sum of the NFT indexes from `sold` to `sold + count - 1`.
+                let indexes = U256::from(count) * U256::from(sold)
+                    + U256::from(count) * U256::from(count.saturating_sub(1)) / 2;
+                base_price.checked_mul(U256::from(count)).unwrap()
+                    + price_increment.checked_mul(indexes).unwrap()
+            }
+        }
+    }
+}
+
+impl DutchAuction {
+    /// Returns the price of one NFT at the given block time.
+    pub fn price_at(&self, block_time: u64) -> U256 {
+        let steps = block_time.saturating_sub(self.start_time) / self.step_interval;
+        let discount = self.price_step.saturating_mul(U256::from(steps));
+        self.start_price
+            .saturating_sub(discount)
+            .max(self.floor_price)
+    }
+}
+
+impl ToBytes for PriceTier {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.supply.to_bytes()?);
+        result.extend(self.price.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.supply.serialized_length() + self.price.serialized_length()
+    }
+}
+
+impl FromBytes for PriceTier {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (supply, bytes) = u64::from_bytes(bytes)?;
+        let (price, bytes) = U256::from_bytes(bytes)?;
+        Ok((PriceTier { supply, price }, bytes))
+    }
+}
+
+impl CLTyped for Pricing {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Pricing {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            Pricing::Fixed => result.push(PRICING_FIXED_TAG),
+            Pricing::DutchAuction(auction) => {
+                result.push(PRICING_DUTCH_AUCTION_TAG);
+                result.extend(auction.start_time.to_bytes()?);
+                result.extend(auction.start_price.to_bytes()?);
+                result.extend(auction.floor_price.to_bytes()?);
+                result.extend(auction.price_step.to_bytes()?);
+                result.extend(auction.step_interval.to_bytes()?);
+                result.extend(auction.rebate.to_bytes()?);
+            }
+            Pricing::Tiered(tiers) => {
+                result.push(PRICING_TIERED_TAG);
+                result.extend(tiers.to_bytes()?);
+            }
+            Pricing::Linear {
+                base_price,
+                price_increment,
+            } => {
+                result.push(PRICING_LINEAR_TAG);
+                result.extend(base_price.to_bytes()?);
+                result.extend(price_increment.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                Pricing::Fixed => 0,
+                Pricing::DutchAuction(auction) => {
+                    auction.start_time.serialized_length()
+                        + auction.start_price.serialized_length()
+                        + auction.floor_price.serialized_length()
+                        + auction.price_step.serialized_length()
+                        + auction.step_interval.serialized_length()
+                        + auction.rebate.serialized_length()
+                }
+                Pricing::Tiered(tiers) => tiers.serialized_length(),
+                Pricing::Linear {
+                    base_price,
+                    price_increment,
+                } => base_price.serialized_length() + price_increment.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for Pricing {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, bytes) = u8::from_bytes(bytes)?;
+        match tag {
+            PRICING_FIXED_TAG => Ok((Pricing::Fixed, bytes)),
+            PRICING_DUTCH_AUCTION_TAG => {
+                let (start_time, bytes) = u64::from_bytes(bytes)?;
+                let (start_price, bytes) = U256::from_bytes(bytes)?;
+                let (floor_price, bytes) = U256::from_bytes(bytes)?;
+                let (price_step, bytes) = U256::from_bytes(bytes)?;
+                let (step_interval, bytes) = u64::from_bytes(bytes)?;
+                let (rebate, bytes) = bool::from_bytes(bytes)?;
+                Ok((
+                    Pricing::DutchAuction(DutchAuction {
+                        start_time,
+                        start_price,
+                        floor_price,
+                        price_step,
+                        step_interval,
+                        rebate,
+                    }),
+                    bytes,
+                ))
+            }
+            PRICING_TIERED_TAG => {
+                let (tiers, bytes) = Vec::<PriceTier>::from_bytes(bytes)?;
+                Ok((Pricing::Tiered(tiers), bytes))
+            }
+            PRICING_LINEAR_TAG => {
+                let (base_price, bytes) = U256::from_bytes(bytes)?;
+                let (price_increment, bytes) = U256::from_bytes(bytes)?;
+                Ok((
+                    Pricing::Linear {
+                        base_price,
+                        price_increment,
+                    },
+                    bytes,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
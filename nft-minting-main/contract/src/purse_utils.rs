@@ -0,0 +1,31 @@
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{ContractPackageHash, Key, RuntimeArgs, URef, U512, runtime_args};
+use contract_utils::ContractStorage;
+
+use crate::data;
+use crate::error::Error;
+
+/// Sends CSPR from the purse to an account, or to a contract package through its receive
+/// entry point. The package gets a new purse holding only the amount, never the source purse.
+pub fn transfer(storage: &impl ContractStorage, source_purse: URef, recipient: Key, amount: U512) {
+    match recipient {
+        Key::Account(account_hash) => {
+            storage.transfer_from_purse_to_account(source_purse, account_hash, amount)
+                .unwrap_or_revert();
+        }
+        Key::Hash(package_hash) => {
+            let purse = storage.create_purse();
+            storage.transfer_from_purse_to_purse(source_purse, purse, amount)
+                .unwrap_or_revert();
+            storage.call_versioned_contract::<()>(
+                ContractPackageHash::new(package_hash),
+                &data::get_receive_entry_point(storage),
+                runtime_args! {
+                    "purse" => purse,
+                    "amount" => amount
+                }
+            );
+        }
+        _ => runtime::revert(Error::InvalidRecipient),
+    }
+}
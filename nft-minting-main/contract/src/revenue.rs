@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, Key, U256,
+};
+
+/// The total of the revenue shares in basis points.
+pub const TOTAL_SHARES: u32 = 10_000;
+
+/// The share of the primary sale proceeds paid to a payee, in basis points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevenueSplit {
+    pub payee: Key,
+    pub share: u32,
+}
+
+/// Splits the amount among the payees by their shares. The rounding dust goes to the
+/// remainder payee so the parts always add up to the amount.
+pub fn split(amount: U256, splits: &[RevenueSplit], remainder_payee: Key) -> Vec<(Key, U256)> {
+    let mut parts: Vec<(Key, U256)> = splits
+        .iter()
+        .map(|split| {
+            let part = amount.checked_mul(U256::from(split.share)).unwrap() / TOTAL_SHARES;
+            (split.payee, part)
+        })
+        .collect();
+    let paid = parts.iter().fold(U256::zero(), |paid, (_, part)| paid + part);
+    let dust = amount - paid;
+    if let Some((_, part)) = parts.iter_mut().find(|(payee, _)| *payee == remainder_payee) {
+        *part += dust;
+    }
+    parts
+}
+
+impl CLTyped for RevenueSplit {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for RevenueSplit {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.payee.to_bytes()?);
+        result.extend(self.share.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.payee.serialized_length() + self.share.serialized_length()
+    }
+}
+
+impl FromBytes for RevenueSplit {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (payee, bytes) = Key::from_bytes(bytes)?;
+        let (share, bytes) = u32::from_bytes(bytes)?;
+        Ok((RevenueSplit { payee, share }, bytes))
+    }
+}
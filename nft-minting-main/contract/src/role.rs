@@ -0,0 +1,69 @@
+use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use casper_types::{CLType, CLTyped};
+use contract_utils::NamedArg;
+
+use crate::data;
+use crate::error::Error;
+
+/// The roles that gate the minter entry points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Role {
+    /// Grants and revokes every role.
+    Admin = 0,
+    /// Updates mint config, metadata, sale phases and payment tokens.
+    ConfigManager = 1,
+    /// Updates the whitelist and the Merkle allowlist.
+    WhitelistManager = 2,
+    /// Mints NFTs without payment.
+    FreeMinter = 3,
+    /// Updates where the mint proceeds go.
+    Treasurer = 4,
+    /// Pauses and unpauses the contract.
+    Guardian = 5,
+}
+
+impl Role {
+    pub const ALL: [Role; 6] = [
+        Role::Admin,
+        Role::ConfigManager,
+        Role::WhitelistManager,
+        Role::FreeMinter,
+        Role::Treasurer,
+        Role::Guardian,
+    ];
+
+    /// Returns the name of the dictionary that stores the role members.
+    pub fn dict_name(&self) -> &'static str {
+        match self {
+            Role::Admin => data::NAMED_KEY_ROLE_ADMIN_DICT,
+            Role::ConfigManager => data::NAMED_KEY_ROLE_CONFIG_MANAGER_DICT,
+            Role::WhitelistManager => data::NAMED_KEY_ROLE_WHITELIST_MANAGER_DICT,
+            Role::FreeMinter => data::NAMED_KEY_ROLE_FREE_MINTER_DICT,
+            Role::Treasurer => data::NAMED_KEY_ROLE_TREASURER_DICT,
+            Role::Guardian => data::NAMED_KEY_ROLE_GUARDIAN_DICT,
+        }
+    }
+}
+
+impl TryFrom<u8> for Role {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Role::ALL
+            .into_iter()
+            .find(|role| *role as u8 == value)
+            .ok_or(Error::InvalidRole)
+    }
+}
+
+/// Roles are passed to entry points by their `u8` value.
+impl NamedArg for Role {
+    fn cl_type() -> CLType {
+        <u8 as CLTyped>::cl_type()
+    }
+
+    fn get(name: &str) -> Self {
+        Role::try_from(<u8 as NamedArg>::get(name)).unwrap_or_revert()
+    }
+}
@@ -0,0 +1,896 @@
+use alloc::{format, string::ToString, vec, vec::Vec};
+use casper_event_standard::{try_full_name_from_bytes, EventInstance};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes},
+    Key, URef, U256, U512,
+};
+use contract_utils::{
+    set_key, ContractContext, ContractStorage, MockContractStorage, FAKE_CEP78_PACKAGE_HASH,
+};
+
+use crate::data::{self, Whitelist};
+use crate::error::Error;
+use crate::events::{AdminChanged, ConfigChanged, Minted, Paused, Unpaused, Withdrawn};
+use crate::migrations::SCHEMA_VERSION;
+use crate::minter::{DEFAULT_RECEIVE_ENTRY_POINT, MINTER, REBATE_CLAIM_PERIOD};
+use crate::merkle;
+use crate::modifiers;
+use crate::phase::Phase;
+use crate::pricing::{DutchAuction, PriceTier, Pricing};
+use crate::role::Role;
+
+const MINT_FEE: u64 = 10;
+const MAX_MINT: u64 = 3;
+const MAX_SUPPLY: u64 = 5;
+
+struct TestMinter(MockContractStorage);
+
+impl ContractContext<MockContractStorage> for TestMinter {
+    fn storage(&self) -> &MockContractStorage {
+        &self.0
+    }
+}
+
+impl MINTER<MockContractStorage> for TestMinter {}
+
+impl TestMinter {
+    /// Installs the minter with `admin()` holding every role.
+    fn install() -> Self {
+        let minter = TestMinter(MockContractStorage::new(admin()));
+        minter.init(
+            admin(),
+            fund_manager(),
+            Key::Hash(FAKE_CEP78_PACKAGE_HASH.value()),
+            U256::from(MINT_FEE),
+            false,
+            true,
+            MAX_MINT,
+            MAX_SUPPLY,
+            "Collection".to_string(),
+            "COL".to_string(),
+            "https://example.com/".to_string(),
+            ".json".to_string(),
+            false,
+        );
+        minter
+    }
+
+    fn as_caller(&mut self, caller: Key) -> &Self {
+        self.0.set_caller(caller);
+        self
+    }
+
+    fn funded_purse(&self, motes: u64) -> URef {
+        self.0.create_funded_purse(U512::from(motes))
+    }
+
+    fn balance(&self, purse: URef) -> U512 {
+        self.0.purse_balance(purse).unwrap()
+    }
+
+    fn mint(&self, nft_owner: Key, count: u64, purse: URef) -> Result<U512, Error> {
+        self.native_mint(nft_owner, count, purse, None, None)
+    }
+}
+
+/// Decodes the emitted events of type `E`, oldest first.
+fn emitted<E: EventInstance + FromBytes>(minter: &TestMinter) -> Vec<E> {
+    let name = format!("event_{}", E::name());
+    minter
+        .storage()
+        .events()
+        .iter()
+        .filter(|bytes| try_full_name_from_bytes(bytes).unwrap() == name)
+        .map(|bytes| bytesrepr::deserialize_from_slice(bytes).unwrap())
+        .collect()
+}
+
+fn account(seed: u8) -> Key {
+    Key::Account(AccountHash::new([seed; 32]))
+}
+
+fn admin() -> Key {
+    account(1)
+}
+
+fn fund_manager() -> Key {
+    account(2)
+}
+
+fn buyer() -> Key {
+    account(3)
+}
+
+fn account_hash(key: Key) -> AccountHash {
+    key.into_account().unwrap()
+}
+
+/// A rebate auction dropping from 30 to the floor of 10 by 10 every 100 milliseconds.
+fn rebate_auction() -> Pricing {
+    Pricing::DutchAuction(DutchAuction {
+        start_time: 0,
+        start_price: U256::from(30),
+        floor_price: U256::from(10),
+        price_step: U256::from(10),
+        step_interval: 100,
+        rebate: true,
+    })
+}
+
+/// Schedules an allowlist phase from 100 to 200 with a max mint of 2, and a public phase
+/// from 300 to 400 with a max mint of 3.
+fn set_two_phases(minter: &TestMinter) {
+    minter
+        .set_sale_phases(
+            vec![100, 300],
+            vec![200, 400],
+            vec![U256::from(MINT_FEE), U256::from(MINT_FEE)],
+            vec![2, 3],
+            vec![true, false],
+        )
+        .unwrap();
+}
+
+/// Sets a Merkle allowlist of `buyer()`, whose leaf carries an allowance of 2, and
+/// `account(4)`, whose leaf carries none, and returns the proof of each.
+fn set_merkle_allowlist(minter: &TestMinter) -> (Vec<[u8; 32]>, Vec<[u8; 32]>) {
+    let storage = minter.storage();
+    let buyer_leaf = merkle::leaf(storage, &buyer(), Some(2));
+    let other_leaf = merkle::leaf(storage, &account(4), None);
+    let root = storage.blake2b(&[buyer_leaf.min(other_leaf), buyer_leaf.max(other_leaf)].concat());
+    minter.set_merkle_root(Some(root)).unwrap();
+    (vec![other_leaf], vec![buyer_leaf])
+}
+
+/// Prices the first two NFTs at 10, the next two at 20 and every later one at 30.
+fn tiered_pricing() -> Pricing {
+    Pricing::Tiered(vec![
+        PriceTier { supply: 2, price: U256::from(10) },
+        PriceTier { supply: 4, price: U256::from(20) },
+        PriceTier { supply: 6, price: U256::from(30) },
+    ])
+}
+
+/// Sells one NFT to `buyer()` at 30 and one to `account(4)` at 20, then ends the auction with
+/// a rebate of 10 owed to `buyer()`.
+fn end_rebate_auction(minter: &mut TestMinter) {
+    minter.set_pricing(rebate_auction()).unwrap();
+    let purse = minter.funded_purse(100);
+    minter.as_caller(buyer()).mint(buyer(), 1, purse).unwrap();
+    minter.storage().set_block_time(100);
+    minter.as_caller(account(4)).mint(account(4), 1, purse).unwrap();
+    minter.as_caller(admin()).end_auction().unwrap();
+}
+
+#[test]
+fn init_grants_every_role_to_admin() {
+    let minter = TestMinter::install();
+    for role in Role::ALL {
+        assert!(minter.has_role(role, admin()));
+        assert!(!minter.has_role(role, buyer()));
+    }
+    assert_eq!(minter.get_config().admin, Some(admin()));
+}
+
+#[test]
+fn free_mint_requires_free_minter_role() {
+    let mut minter = TestMinter::install();
+    let result = minter.as_caller(buyer()).free_mint(buyer(), 1);
+    assert!(matches!(result, Err(Error::PermissionDenied)));
+
+    minter.as_caller(admin()).grant_role(Role::FreeMinter, buyer()).unwrap();
+    minter.as_caller(buyer()).free_mint(buyer(), 2).unwrap();
+
+    let cep78 = minter.storage().cep78();
+    assert_eq!(cep78.balance_of(&buyer()), 2);
+    assert_eq!(cep78.owner_of(1), Some(buyer()));
+    assert!(cep78
+        .metadata_of(1)
+        .unwrap()
+        .contains(r#""token_uri": "https://example.com/1.json""#));
+    drop(cep78);
+    assert_eq!(minter.minted_by(buyer()), 2);
+    assert_eq!(minter.get_remaining_supply(), MAX_SUPPLY - 2);
+}
+
+#[test]
+fn metadata_escapes_collection_values() {
+    let minter = TestMinter::install();
+    minter
+        .set_metadata(Some(r#"Say "hi" \ bye"#.to_string()), None, None, None)
+        .unwrap();
+    minter.free_mint(buyer(), 1).unwrap();
+
+    let metadata = minter.storage().cep78().metadata_of(0).unwrap().to_string();
+    assert!(metadata.contains(r#""name": "Say \"hi\" \\ bye""#));
+    assert!(metadata.contains(r#""symbol": "COL""#));
+}
+
+#[test]
+fn free_mint_stays_within_max_supply() {
+    let minter = TestMinter::install();
+    minter.free_mint(buyer(), MAX_SUPPLY).unwrap();
+    let result = minter.free_mint(buyer(), 1);
+    assert!(matches!(result, Err(Error::SupplyExceeded)));
+    assert_eq!(minter.storage().cep78().total_minted(), MAX_SUPPLY);
+}
+
+#[test]
+fn airdrop_mints_the_whole_batch_or_nothing() {
+    let minter = TestMinter::install();
+    let events = minter.storage().events().len();
+    let total = minter.airdrop(vec![buyer(), account(4)], vec![2, 1]).unwrap();
+    assert_eq!(total, 3);
+    assert_eq!(minter.storage().cep78().balance_of(&account(4)), 1);
+    assert_eq!(minter.storage().events().len(), events + 1);
+
+    let result = minter.airdrop(vec![buyer(), account(4)], vec![1, 2]);
+    assert!(matches!(result, Err(Error::SupplyExceeded)));
+    assert_eq!(minter.storage().cep78().total_minted(), 3);
+    let result = minter.airdrop(Vec::new(), Vec::new());
+    assert!(matches!(result, Err(Error::WrongArguments)));
+}
+
+#[test]
+fn parallel_lists_must_have_the_same_length() {
+    let minter = TestMinter::install();
+    let result = minter.airdrop(vec![buyer(), account(4)], vec![1]);
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    let result = minter.set_whitelist(vec![buyer()], Vec::new());
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    let result = minter.set_whitelist_entries(vec![buyer()], vec![None], Vec::new(), vec![None]);
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    let result =
+        minter.set_sale_phases(vec![0], vec![1_000], vec![U256::one()], vec![1], Vec::new());
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    let result = minter.set_tiered_pricing(vec![1, 2], vec![U256::one()]);
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    let result = minter.set_revenue_splits(vec![buyer()], Vec::new(), Some(buyer()));
+    assert!(matches!(result, Err(Error::WrongArguments)));
+    assert_eq!(minter.storage().cep78().total_minted(), 0);
+}
+
+#[test]
+fn native_mint_pays_the_fund_manager() {
+    let mut minter = TestMinter::install();
+    let purse = minter.funded_purse(100);
+    let paid = minter.as_caller(buyer()).mint(buyer(), 2, purse).unwrap();
+    assert_eq!(paid, U512::from(2 * MINT_FEE));
+    assert_eq!(minter.balance(purse), U512::from(100 - 2 * MINT_FEE));
+    assert_eq!(
+        minter.storage().account_balance(account_hash(fund_manager())),
+        U512::from(2 * MINT_FEE)
+    );
+    assert_eq!(minter.storage().cep78().balance_of(&buyer()), 2);
+}
+
+#[test]
+fn native_mint_requires_enough_balance() {
+    let minter = TestMinter::install();
+    let purse = minter.funded_purse(MINT_FEE - 1);
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::NotEnoughBalance)));
+    assert_eq!(minter.storage().cep78().total_minted(), 0);
+}
+
+#[test]
+fn native_mint_respects_max_mint() {
+    let minter = TestMinter::install();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), MAX_MINT, purse).unwrap();
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::MintLimitExceed)));
+    assert_eq!(minter.remaining_for(buyer()), 0);
+}
+
+#[test]
+fn max_mint_check_does_not_wrap_around() {
+    let minter = TestMinter::install();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 1, purse).unwrap();
+    let result = modifiers::limited_mint(minter.storage(), buyer(), u64::MAX, MAX_MINT);
+    assert!(matches!(result, Err(Error::MintLimitExceed)));
+}
+
+#[test]
+fn native_mint_pays_the_treasury_when_enabled() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, None, None, None, None, Some(true), None)
+        .unwrap();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 1, purse).unwrap();
+    assert_eq!(minter.get_treasury_balance(), U512::from(MINT_FEE));
+    assert!(minter
+        .storage()
+        .account_balance(account_hash(fund_manager()))
+        .is_zero());
+
+    let result = minter.withdraw(buyer(), Some(U512::from(MINT_FEE + 1)));
+    assert!(matches!(result, Err(Error::NotEnoughBalance)));
+    minter.withdraw(buyer(), None).unwrap();
+    assert_eq!(
+        minter.storage().account_balance(account_hash(buyer())),
+        U512::from(MINT_FEE)
+    );
+}
+
+#[test]
+fn proceeds_to_a_contract_package_go_through_its_receive_entry_point() {
+    let minter = TestMinter::install();
+    let fund_manager = Key::Hash([0x33; 32]);
+    minter
+        .set_config(Some(fund_manager), None, None, None, None, None, None)
+        .unwrap();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 1, purse).unwrap();
+
+    let calls = minter.storage().calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].package_hash.value(), [0x33; 32]);
+    assert_eq!(calls[0].entry_point, DEFAULT_RECEIVE_ENTRY_POINT);
+    let amount: U512 = calls[0].args.get("amount").cloned().unwrap().into_t().unwrap();
+    assert_eq!(amount, U512::from(MINT_FEE));
+    // The payee is called back only once the mint is recorded.
+    assert_eq!(calls[0].events_before, minter.storage().events().len());
+}
+
+#[test]
+fn token_mints_pay_with_the_token_once_the_mint_is_recorded() {
+    let mut minter = TestMinter::install();
+    let payment_token = Key::Hash([0x18; 32]);
+    minter
+        .set_payment_token(payment_token, U256::from(MINT_FEE))
+        .unwrap();
+    minter
+        .as_caller(buyer())
+        .token_mint(buyer(), 2, payment_token, None, None)
+        .unwrap();
+    assert_eq!(minter.minted_by(buyer()), 2);
+
+    let calls = minter.storage().calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].package_hash.value(), [0x18; 32]);
+    assert_eq!(calls[0].entry_point, "transfer_from");
+    let amount: U256 = calls[0].args.get("amount").cloned().unwrap().into_t().unwrap();
+    assert_eq!(amount, U256::from(2 * MINT_FEE));
+    assert_eq!(calls[0].events_before, minter.storage().events().len());
+}
+
+#[test]
+fn set_config_requires_roles() {
+    let mut minter = TestMinter::install();
+    let result = minter
+        .as_caller(buyer())
+        .set_config(None, Some(U256::one()), None, None, None, None, None);
+    assert!(matches!(result, Err(Error::PermissionDenied)));
+    let result = minter
+        .as_caller(buyer())
+        .set_config(None, None, None, None, None, Some(true), None);
+    assert!(matches!(result, Err(Error::PermissionDenied)));
+    assert_eq!(minter.get_config().mint_fee, U256::from(MINT_FEE));
+}
+
+#[test]
+fn only_whitelisted_accounts_can_mint() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, None, Some(true), None, None, None, None)
+        .unwrap();
+    let purse = minter.funded_purse(100);
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::NotWhitelisted)));
+    assert!(!minter.is_whitelisted(buyer()));
+
+    minter.set_whitelist(vec![buyer()], vec![true]).unwrap();
+    assert!(minter.is_whitelisted(buyer()));
+    minter.mint(buyer(), 1, purse).unwrap();
+}
+
+#[test]
+fn whitelist_entries_set_allowance_and_price_until_expiry() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, None, Some(true), None, None, None, None)
+        .unwrap();
+    minter
+        .set_whitelist_entries(
+            vec![buyer()],
+            vec![Some(4)],
+            vec![Some(U256::one())],
+            vec![Some(1_000)],
+        )
+        .unwrap();
+    assert_eq!(minter.remaining_for(buyer()), 4);
+    assert_eq!(
+        minter.get_mint_cost(4, Some(buyer())).unwrap(),
+        U256::from(4)
+    );
+
+    let purse = minter.funded_purse(100);
+    assert_eq!(minter.mint(buyer(), 4, purse).unwrap(), U512::from(4));
+
+    minter.storage().set_block_time(1_000);
+    assert!(!minter.is_whitelisted(buyer()));
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::NotWhitelisted)));
+}
+
+#[test]
+fn minted_events_record_the_mint_and_its_payment() {
+    let mut minter = TestMinter::install();
+    let payment_token = Key::Hash([0x18; 32]);
+    minter
+        .set_payment_token(payment_token, U256::from(MINT_FEE))
+        .unwrap();
+    minter.free_mint(account(4), 1).unwrap();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 2, purse).unwrap();
+    minter
+        .as_caller(buyer())
+        .token_mint(buyer(), 1, payment_token, None, None)
+        .unwrap();
+
+    assert_eq!(
+        emitted::<Minted>(&minter),
+        vec![
+            Minted {
+                owner: account(4),
+                count: 1,
+                first_index: 0,
+                paid: U256::zero(),
+                payment_token: None,
+            },
+            Minted {
+                owner: buyer(),
+                count: 2,
+                first_index: 1,
+                paid: U256::from(2 * MINT_FEE),
+                payment_token: None,
+            },
+            Minted {
+                owner: buyer(),
+                count: 1,
+                first_index: 3,
+                paid: U256::from(MINT_FEE),
+                payment_token: Some(payment_token),
+            },
+        ]
+    );
+}
+
+#[test]
+fn config_changed_events_carry_the_old_and_new_values() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, Some(U256::from(15)), None, None, Some(5), None, None)
+        .unwrap();
+
+    assert_eq!(
+        emitted::<ConfigChanged>(&minter),
+        vec![
+            ConfigChanged {
+                field: data::NAMED_KEY_MINT_FEE.to_string(),
+                old: MINT_FEE.to_string(),
+                new: "15".to_string(),
+            },
+            ConfigChanged {
+                field: data::NAMED_KEY_MAX_MIN.to_string(),
+                old: MAX_MINT.to_string(),
+                new: "5".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn admin_changed_events_follow_the_admin_role() {
+    let mut minter = TestMinter::install();
+    let events = emitted::<AdminChanged>(&minter).len();
+    minter.grant_role(Role::Admin, account(4)).unwrap();
+    minter.revoke_role(Role::Admin, account(4)).unwrap();
+    minter.transfer_admin(buyer()).unwrap();
+    minter.as_caller(buyer()).accept_admin().unwrap();
+
+    assert_eq!(
+        emitted::<AdminChanged>(&minter)[events..],
+        [
+            AdminChanged {
+                previous_admin: None,
+                new_admin: Some(account(4)),
+            },
+            AdminChanged {
+                previous_admin: Some(account(4)),
+                new_admin: None,
+            },
+            AdminChanged {
+                previous_admin: Some(admin()),
+                new_admin: Some(buyer()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn pause_events_name_the_guardian() {
+    let minter = TestMinter::install();
+    minter.pause(7, Some(100)).unwrap();
+    minter.unpause().unwrap();
+
+    assert_eq!(
+        emitted::<Paused>(&minter),
+        vec![Paused {
+            guardian: admin(),
+            reason: 7,
+            until: Some(100),
+        }]
+    );
+    assert_eq!(
+        emitted::<Unpaused>(&minter),
+        vec![Unpaused { guardian: admin() }]
+    );
+}
+
+#[test]
+fn withdrawn_events_record_the_recipient_and_amount() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, None, None, None, None, Some(true), None)
+        .unwrap();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 2, purse).unwrap();
+    minter.withdraw(fund_manager(), None).unwrap();
+
+    assert_eq!(
+        emitted::<Withdrawn>(&minter),
+        vec![Withdrawn {
+            recipient: fund_manager(),
+            amount: U512::from(2 * MINT_FEE),
+        }]
+    );
+}
+
+#[test]
+fn active_phase_follows_the_block_time() {
+    let minter = TestMinter::install();
+    assert_eq!(minter.get_active_phase().unwrap().max_mint, MAX_MINT);
+    set_two_phases(&minter);
+
+    for block_time in [0, 99, 200, 299, 400] {
+        minter.storage().set_block_time(block_time);
+        assert!(matches!(minter.get_active_phase(), Err(Error::NoActivePhase)));
+    }
+    minter.storage().set_block_time(100);
+    assert!(minter.get_active_phase().unwrap().only_whitelist);
+    minter.storage().set_block_time(399);
+    assert_eq!(minter.get_active_phase().unwrap().start_time, 300);
+
+    let purse = minter.funded_purse(100);
+    minter.storage().set_block_time(250);
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::NoActivePhase)));
+}
+
+#[test]
+fn phases_must_not_overlap() {
+    let phase = |start_time, end_time| Phase {
+        start_time,
+        end_time,
+        mint_fee: U256::one(),
+        max_mint: 1,
+        only_whitelist: false,
+    };
+    assert!(modifiers::valid_phases(&[phase(0, 100), phase(100, 200)]).is_ok());
+    for phases in [
+        vec![phase(0, 150), phase(100, 200)],
+        vec![phase(100, 200), phase(0, 50)],
+        vec![phase(100, 100)],
+    ] {
+        assert!(matches!(
+            modifiers::valid_phases(&phases),
+            Err(Error::InvalidPhase)
+        ));
+    }
+
+    let minter = TestMinter::install();
+    let result = minter.set_sale_phases(
+        vec![0, 100],
+        vec![150, 200],
+        vec![U256::one(), U256::one()],
+        vec![1, 1],
+        vec![false, false],
+    );
+    assert!(matches!(result, Err(Error::InvalidPhase)));
+}
+
+#[test]
+fn phase_max_mints_count_earlier_phases() {
+    let minter = TestMinter::install();
+    set_two_phases(&minter);
+    minter.set_whitelist(vec![buyer()], vec![true]).unwrap();
+    let purse = minter.funded_purse(100);
+
+    minter.storage().set_block_time(100);
+    minter.mint(buyer(), 2, purse).unwrap();
+    minter.storage().set_block_time(300);
+    assert_eq!(minter.remaining_for(buyer()), 1);
+    let result = minter.mint(buyer(), 2, purse);
+    assert!(matches!(result, Err(Error::MintLimitExceed)));
+    minter.mint(buyer(), 1, purse).unwrap();
+}
+
+#[test]
+fn tiered_pricing_prices_every_nft_of_a_batch_in_its_tier() {
+    let pricing = tiered_pricing();
+    assert_eq!(pricing.cost(U256::zero(), 0, 0, 2), U256::from(20));
+    // One boundary: the 2nd NFT at 10 and the 3rd at 20.
+    assert_eq!(pricing.cost(U256::zero(), 0, 1, 2), U256::from(30));
+    // Several boundaries: 10, 20, 20 and 30.
+    assert_eq!(pricing.cost(U256::zero(), 0, 1, 4), U256::from(80));
+}
+
+#[test]
+fn tiered_pricing_keeps_the_last_tier_price_once_exhausted() {
+    let pricing = tiered_pricing();
+    assert_eq!(pricing.cost(U256::zero(), 0, 5, 3), U256::from(90));
+    assert_eq!(pricing.cost(U256::zero(), 0, 100, 2), U256::from(60));
+}
+
+#[test]
+fn linear_pricing_raises_the_price_with_every_nft_sold() {
+    let pricing = Pricing::Linear {
+        base_price: U256::from(10),
+        price_increment: U256::from(2),
+    };
+    assert_eq!(pricing.cost(U256::zero(), 0, 0, 1), U256::from(10));
+    // 16, 18 and 20.
+    assert_eq!(pricing.cost(U256::zero(), 0, 3, 3), U256::from(54));
+    assert_eq!(pricing.cost(U256::zero(), 0, 3, 0), U256::zero());
+}
+
+#[test]
+fn price_tiers_must_be_ordered_by_supply() {
+    assert!(modifiers::valid_pricing(&tiered_pricing()).is_ok());
+    let tiers = |supplies: &[u64]| {
+        Pricing::Tiered(
+            supplies
+                .iter()
+                .map(|&supply| PriceTier { supply, price: U256::one() })
+                .collect(),
+        )
+    };
+    for pricing in [tiers(&[]), tiers(&[4, 2]), tiers(&[2, 2])] {
+        assert!(matches!(
+            modifiers::valid_pricing(&pricing),
+            Err(Error::InvalidPricing)
+        ));
+    }
+
+    let minter = TestMinter::install();
+    let result = minter.set_tiered_pricing(vec![4, 2], vec![U256::one(), U256::one()]);
+    assert!(matches!(result, Err(Error::InvalidPricing)));
+}
+
+#[test]
+fn native_mint_charges_a_batch_crossing_tiers_per_unit() {
+    let minter = TestMinter::install();
+    minter
+        .set_tiered_pricing(vec![2, 4], vec![U256::from(10), U256::from(20)])
+        .unwrap();
+    let purse = minter.funded_purse(100);
+    minter.mint(buyer(), 1, purse).unwrap();
+    assert_eq!(minter.get_mint_cost(2, None).unwrap(), U256::from(30));
+    assert_eq!(minter.mint(account(4), 2, purse).unwrap(), U512::from(30));
+}
+
+#[test]
+fn merkle_proof_allows_minting_with_or_without_allowance() {
+    let minter = TestMinter::install();
+    minter
+        .set_config(None, None, Some(true), None, None, None, None)
+        .unwrap();
+    let (buyer_proof, other_proof) = set_merkle_allowlist(&minter);
+    let purse = minter.funded_purse(100);
+
+    minter
+        .native_mint(buyer(), 2, purse, Some(buyer_proof), Some(2))
+        .unwrap();
+    minter
+        .native_mint(account(4), MAX_MINT, purse, Some(other_proof), None)
+        .unwrap();
+    assert_eq!(minter.minted_by(buyer()), 2);
+    assert_eq!(minter.minted_by(account(4)), MAX_MINT);
+}
+
+#[test]
+fn merkle_proof_must_match_the_account_and_allowance() {
+    let minter = TestMinter::install();
+    let (buyer_proof, other_proof) = set_merkle_allowlist(&minter);
+    let purse = minter.funded_purse(100);
+
+    let result = minter.native_mint(account(4), 1, purse, Some(buyer_proof.clone()), Some(2));
+    assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+    let result = minter.native_mint(account(5), 1, purse, Some(other_proof), None);
+    assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+    let result = minter.native_mint(buyer(), 1, purse, Some(buyer_proof.clone()), Some(3));
+    assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+    let result = minter.native_mint(buyer(), 1, purse, Some(buyer_proof), None);
+    assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+    assert_eq!(minter.storage().cep78().total_minted(), 0);
+}
+
+#[test]
+fn merkle_proof_requires_a_root() {
+    let minter = TestMinter::install();
+    let (buyer_proof, _) = set_merkle_allowlist(&minter);
+    minter.set_merkle_root(None).unwrap();
+    let purse = minter.funded_purse(100);
+
+    let result = minter.native_mint(buyer(), 1, purse, Some(buyer_proof), Some(2));
+    assert!(matches!(result, Err(Error::InvalidMerkleProof)));
+}
+
+#[test]
+fn merkle_allowance_caps_the_mint_over_whitelist_entries() {
+    let minter = TestMinter::install();
+    let (buyer_proof, _) = set_merkle_allowlist(&minter);
+    minter
+        .set_whitelist_entries(vec![buyer()], vec![Some(4)], vec![None], vec![None])
+        .unwrap();
+    let purse = minter.funded_purse(100);
+
+    let result = minter.native_mint(buyer(), 3, purse, Some(buyer_proof.clone()), Some(2));
+    assert!(matches!(result, Err(Error::MintLimitExceed)));
+    minter
+        .native_mint(buyer(), 2, purse, Some(buyer_proof.clone()), Some(2))
+        .unwrap();
+    let result = minter.native_mint(buyer(), 1, purse, Some(buyer_proof), Some(2));
+    assert!(matches!(result, Err(Error::MintLimitExceed)));
+
+    // Without a proof the whitelist entry allowance applies.
+    minter.mint(buyer(), 2, purse).unwrap();
+    assert_eq!(minter.minted_by(buyer()), 4);
+}
+
+#[test]
+fn rebates_can_be_claimed_until_the_deadline() {
+    let mut minter = TestMinter::install();
+    end_rebate_auction(&mut minter);
+    assert_eq!(minter.get_rebate(buyer()), U256::from(10));
+    assert!(matches!(
+        minter.set_pricing(rebate_auction()),
+        Err(Error::RebatesPending)
+    ));
+
+    minter.storage().set_block_time(100 + REBATE_CLAIM_PERIOD - 1);
+    assert_eq!(minter.as_caller(buyer()).claim_rebate().unwrap(), U512::from(10));
+    assert_eq!(minter.storage().account_balance(account_hash(buyer())), U512::from(10));
+    minter.as_caller(admin()).set_pricing(rebate_auction()).unwrap();
+}
+
+#[test]
+fn unclaimed_rebates_are_swept_after_the_deadline() {
+    let mut minter = TestMinter::install();
+    end_rebate_auction(&mut minter);
+    let result = minter.sweep_rebates(admin());
+    assert!(matches!(result, Err(Error::RebatesPending)));
+
+    minter.storage().set_block_time(100 + REBATE_CLAIM_PERIOD);
+    assert_eq!(minter.get_rebate(buyer()), U256::zero());
+    let result = minter.as_caller(buyer()).claim_rebate();
+    assert!(matches!(result, Err(Error::RebateClaimExpired)));
+    let result = minter.sweep_rebates(buyer());
+    assert!(matches!(result, Err(Error::PermissionDenied)));
+
+    assert_eq!(minter.as_caller(admin()).sweep_rebates(admin()).unwrap(), U512::from(10));
+    assert_eq!(minter.storage().account_balance(account_hash(admin())), U512::from(10));
+    assert!(matches!(minter.sweep_rebates(admin()), Err(Error::NoRebate)));
+    minter.set_pricing(rebate_auction()).unwrap();
+}
+
+#[test]
+fn pause_blocks_minting_until_it_ends() {
+    let minter = TestMinter::install();
+    minter.pause(7, Some(100)).unwrap();
+    assert!(minter.is_paused());
+    let purse = minter.funded_purse(100);
+    let result = minter.mint(buyer(), 1, purse);
+    assert!(matches!(result, Err(Error::Paused)));
+
+    minter.storage().set_block_time(100);
+    assert!(!minter.is_paused());
+    minter.mint(buyer(), 1, purse).unwrap();
+}
+
+#[test]
+fn admin_transfer_needs_acceptance() {
+    let mut minter = TestMinter::install();
+    minter.transfer_admin(buyer()).unwrap();
+    assert_eq!(minter.get_pending_admin(), Some(buyer()));
+
+    let result = minter.as_caller(account(4)).accept_admin();
+    assert!(result.is_err());
+    minter.as_caller(buyer()).accept_admin().unwrap();
+    assert!(minter.has_role(Role::Admin, buyer()));
+    assert_eq!(minter.get_config().admin, Some(buyer()));
+}
+
+#[test]
+fn migrate_runs_once_per_contract_version() {
+    let minter = TestMinter::install();
+    assert_eq!(data::get_schema_version(minter.storage()), SCHEMA_VERSION);
+    assert!(matches!(minter.migrate(1), Err(Error::AlreadyMigrated)));
+
+    minter.migrate(2).unwrap();
+    assert!(matches!(minter.migrate(2), Err(Error::AlreadyMigrated)));
+    assert_eq!(data::get_migrated_version(minter.storage()), 2);
+}
+
+#[test]
+fn migrate_upgrades_the_first_release_layout() {
+    let storage = MockContractStorage::new(admin());
+    set_key(&storage, data::NAMED_KEY_ADMIN, admin());
+    set_key(&storage, data::NAMED_KEY_FUND_MANAGER, fund_manager());
+    set_key(
+        &storage,
+        data::NAMED_KEY_CEP78_PACKAGE_HASH,
+        Key::Hash(FAKE_CEP78_PACKAGE_HASH.value()),
+    );
+    set_key(&storage, data::NAMED_KEY_MINT_FEE, U256::from(MINT_FEE));
+    set_key(&storage, data::NAMED_KEY_MINT_COUNT, 7u64);
+    set_key(&storage, data::NAMED_KEY_MAX_MIN, MAX_MINT);
+    set_key(&storage, data::NAMED_KEY_ONLY_WHITELIST, true);
+    set_key(&storage, data::NAMED_KEY_ALLOW_MINT, true);
+    Whitelist::init(&storage);
+    Whitelist::instance(&storage).set(&buyer(), true);
+    let mut minter = TestMinter(storage);
+    assert_eq!(data::get_schema_version(minter.storage()), 0);
+
+    minter.migrate(2).unwrap();
+    assert_eq!(data::get_schema_version(minter.storage()), SCHEMA_VERSION);
+    assert!(!minter.is_upgrade_key_dropped());
+    assert_eq!(data::get_rebate_deadline(minter.storage()), None);
+    assert_eq!(minter.get_config().admin, Some(admin()));
+    for role in Role::ALL {
+        assert!(minter.has_role(role, admin()));
+    }
+
+    let purse = minter.funded_purse(100);
+    minter.as_caller(buyer()).mint(buyer(), MAX_MINT, purse).unwrap();
+    assert_eq!(minter.get_config().mint_count, 7 + MAX_MINT);
+    let metadata = minter.storage().cep78().metadata_of(0).unwrap().to_string();
+    assert!(metadata.contains(r#""symbol": "BAYC""#));
+    assert!(metadata.contains(r#"jxcaWtq/7""#));
+    let result = minter.mint(account(4), 1, purse);
+    assert!(matches!(result, Err(Error::NotWhitelisted)));
+    minter.as_caller(admin()).pause(0, None).unwrap();
+}
+
+#[test]
+fn migrate_refuses_storage_from_a_newer_schema() {
+    let minter = TestMinter::install();
+    data::set_schema_version(minter.storage(), SCHEMA_VERSION + 1);
+    let result = minter.migrate(2);
+    assert!(matches!(result, Err(Error::UnsupportedSchemaVersion)));
+    assert_eq!(data::get_migrated_version(minter.storage()), 1);
+}
+
+#[test]
+fn finalize_records_the_dropped_upgrade_key_once() {
+    let minter = TestMinter::install();
+    assert!(!minter.get_config().upgrade_key_dropped);
+
+    minter.finalize().unwrap();
+    assert!(minter.is_upgrade_key_dropped());
+    assert!(minter.get_config().upgrade_key_dropped);
+    assert!(matches!(minter.finalize(), Err(Error::UpgradeKeyDropped)));
+}
+
+#[test]
+#[should_panic(expected = "reverted with code")]
+fn transfer_out_of_an_empty_purse_reverts() {
+    let storage = MockContractStorage::default();
+    let purse = storage.create_purse();
+    crate::purse_utils::transfer(&storage, purse, buyer(), U512::one());
+}
@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+/// A whitelisted account with its own minting terms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhitelistEntry {
+    /// The maximum number of NFTs the account is allowed to mint, the phase max mint when unset.
+    pub allowance: Option<u64>,
+    /// The CSPR price of one NFT for the account, replacing the pricing strategy when set.
+    pub price: Option<U256>,
+    /// Block time in milliseconds from which the entry is no longer valid.
+    pub expiry: Option<u64>,
+}
+
+impl WhitelistEntry {
+    /// Returns `true` if the entry is still valid at the given block time.
+    pub fn is_valid(&self, block_time: u64) -> bool {
+        self.expiry.map_or(true, |expiry| block_time < expiry)
+    }
+}
+
+impl CLTyped for WhitelistEntry {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for WhitelistEntry {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.allowance.to_bytes()?);
+        result.extend(self.price.to_bytes()?);
+        result.extend(self.expiry.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.allowance.serialized_length()
+            + self.price.serialized_length()
+            + self.expiry.serialized_length()
+    }
+}
+
+impl FromBytes for WhitelistEntry {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (allowance, bytes) = Option::<u64>::from_bytes(bytes)?;
+        let (price, bytes) = Option::<U256>::from_bytes(bytes)?;
+        let (expiry, bytes) = Option::<u64>::from_bytes(bytes)?;
+        Ok((
+            WhitelistEntry {
+                allowance,
+                price,
+                expiry,
+            },
+            bytes,
+        ))
+    }
+}
@@ -4,8 +4,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
 
+extern crate alloc;
+use alloc::vec::Vec;
+
 use casper_contract::{
-    contract_api::{account, runtime, system},
+    contract_api::{account, runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
@@ -14,15 +17,20 @@ use casper_types::{
     U512, URef, Key, 
     ContractPackageHash
 };
+use contract_utils::get_optional_named_arg;
 
 const ARG_NFT_OWMER: &str = "nft_owner";
 const ARG_COUNT: &str = "count";
 const ARG_AMOUNT: &str = "amount";
 const ARG_MINTER_PACKAGE_HASH: &str = "minter_package_hash";
 const ARG_SOURCE_PURSE: &str = "source_purse";
+const ARG_MERKLE_PROOF: &str = "merkle_proof";
+const ARG_MERKLE_ALLOWANCE: &str = "merkle_allowance";
 
 const ENTRY_POINT_NATIVE_MINT: &str = "native_mint"; 
 
+const NAMED_KEY_LAST_MINT_CHARGED: &str = "minter_last_mint_charged";
+
 fn purse(amount: U512) -> URef {
     let main_purse: URef = account::get_main_purse();
     let secondary_purse: URef = system::create_purse();
@@ -31,6 +39,28 @@ fn purse(amount: U512) -> URef {
     secondary_purse
 }
 
+// Sends whatever the minter didn't charge back to the caller's main purse.
+fn refund(purse: URef) {
+    let leftover = system::get_purse_balance(purse).unwrap_or_revert();
+    if !leftover.is_zero() {
+        system::transfer_from_purse_to_purse(purse, account::get_main_purse(), leftover, None)
+            .unwrap_or_revert();
+    }
+}
+
+// Records the amount charged by the last mint under the caller's named keys.
+fn record_charged(charged: U512) {
+    match runtime::get_key(NAMED_KEY_LAST_MINT_CHARGED) {
+        Some(key) => {
+            let uref = key.into_uref().unwrap_or_revert();
+            storage::write(uref, charged);
+        }
+        None => {
+            runtime::put_key(NAMED_KEY_LAST_MINT_CHARGED, storage::new_uref(charged).into());
+        }
+    }
+}
+
 #[no_mangle]
 fn call() {
     let nft_owner = runtime::get_named_arg::<Key>(ARG_NFT_OWMER);
@@ -41,14 +71,26 @@ fn call() {
         .map(ContractPackageHash::new)
         .unwrap();
 
-    runtime::call_versioned_contract(
+    let source_purse = purse(amount);
+    let mut native_mint_args = runtime_args! {
+        ARG_NFT_OWMER => nft_owner,
+        ARG_COUNT => count,
+        ARG_SOURCE_PURSE => source_purse
+    };
+    if let Some(merkle_proof) = get_optional_named_arg::<Vec<[u8; 32]>>(ARG_MERKLE_PROOF) {
+        native_mint_args.insert(ARG_MERKLE_PROOF, Some(merkle_proof)).unwrap_or_revert();
+    }
+    if let Some(merkle_allowance) = get_optional_named_arg::<u64>(ARG_MERKLE_ALLOWANCE) {
+        native_mint_args.insert(ARG_MERKLE_ALLOWANCE, Some(merkle_allowance)).unwrap_or_revert();
+    }
+
+    let charged: U512 = runtime::call_versioned_contract(
         minter_package_hash,
         None,
         ENTRY_POINT_NATIVE_MINT,
-        runtime_args! {
-            ARG_NFT_OWMER => nft_owner,
-            ARG_COUNT => count,
-            ARG_SOURCE_PURSE => purse(amount)
-        }
-    )
+        native_mint_args
+    );
+
+    refund(source_purse);
+    record_charged(charged);
 }
@@ -9,6 +9,8 @@ import {
   setConfig,
   nativeMint,
   setWhitelist,
+  setPaymentToken,
+  tokenMint,
   readMinterContract,
   isAccWhitelistedToMint,
 } from './minter/actions';
@@ -38,6 +40,8 @@ async function main() {
   // await setWhitelist();
   // await setConfig();
   await nativeMint();
+  // await setPaymentToken();
+  // await tokenMint();
   // await freeMint();
   // await readMinterContract();
   // await isAccWhitelistedToMint();
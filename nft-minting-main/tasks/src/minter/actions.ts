@@ -1,11 +1,12 @@
 import { MinterClient } from './client';
-import { NETWORK, NODE_ADDRESS, MINTER_CONTRACT } from '../constants';
+import { Role } from './client/types';
+import { NETWORK, NODE_ADDRESS, MINTER_CONTRACT, WETH_CONTRACT } from '../constants';
 import { AdminKeypair, User1Keypair } from '../accounts';
 
 const minterClient = new MinterClient(NODE_ADDRESS, NETWORK);
 minterClient.setContractHash(MINTER_CONTRACT.contractHash, MINTER_CONTRACT.packageHash);
 
-//only admin
+//only whitelist manager
 export async function setWhitelist() {
   const users = [
     {
@@ -58,10 +59,10 @@ export async function setWhitelist() {
   console.log('deployHash', deployHash);
 }
 
-//only admin
+//only config manager
 export async function setConfig() {
   const deploy = minterClient.setConfig(
-    { adminAccountHash: User1Keypair.publicKey.toAccountHashStr() },
+    { maxMint: 30 },
     AdminKeypair.publicKey,
     [AdminKeypair]
   );
@@ -70,7 +71,7 @@ export async function setConfig() {
   console.log('deployHash', deployHash);
 }
 
-//only admin
+//only free minter
 export async function freeMint() {
   const deploy = minterClient.freeMint(
     {
@@ -86,7 +87,10 @@ export async function freeMint() {
 
 export async function nativeMint() {
   const NFT_COUNT = 3;
-  const mintCost = await minterClient.getMintCost(NFT_COUNT);
+  const mintCost = await minterClient.getMintCost(
+    NFT_COUNT,
+    User1Keypair.publicKey.toAccountHashStr()
+  );
   const deploy = minterClient.nativeMint(
     {
       nftOwnerAccountHash: User1Keypair.publicKey.toAccountHashStr(),
@@ -101,6 +105,50 @@ export async function nativeMint() {
   console.log('deployHash', deployHash);
 }
 
+//only config manager
+export async function setPaymentToken() {
+  const deploy = minterClient.setPaymentToken(
+    {
+      paymentTokenPackageHash: WETH_CONTRACT.packageHash,
+      tokenMintFee: 10e9,
+    },
+    AdminKeypair.publicKey,
+    [AdminKeypair]
+  );
+  const deployHash = await deploy.send(NODE_ADDRESS);
+  console.log('deployHash', deployHash);
+}
+
+export async function tokenMint() {
+  const deploy = minterClient.tokenMint(
+    {
+      nftOwnerAccountHash: User1Keypair.publicKey.toAccountHashStr(),
+      nftCount: 3,
+      paymentTokenPackageHash: WETH_CONTRACT.packageHash,
+    },
+    User1Keypair.publicKey,
+    [User1Keypair]
+  );
+  const deployHash = await deploy.send(NODE_ADDRESS);
+  console.log('deployHash', deployHash);
+}
+
+//only admin
+export async function grantFreeMinterRole() {
+  const deploy = minterClient.setRole(
+    'grant_role',
+    {
+      role: Role.FREE_MINTER,
+      accountHash: User1Keypair.publicKey.toAccountHashStr(),
+    },
+    AdminKeypair.publicKey,
+    [AdminKeypair]
+  );
+
+  const deployHash = await deploy.send(NODE_ADDRESS);
+  console.log('deployHash', deployHash);
+}
+
 export async function readMinterContract() {
   const [
     admin,
@@ -112,7 +160,7 @@ export async function readMinterContract() {
     allowMint,
     maxMint,
   ] = await Promise.all([
-    minterClient.admin(),
+    minterClient.hasRole(Role.ADMIN, AdminKeypair.publicKey.toAccountHashStr()),
     minterClient.fundManager(),
     minterClient.cep78PackageHash(),
     minterClient.mintFee(),
@@ -123,7 +171,7 @@ export async function readMinterContract() {
   ]);
 
   console.log({
-    admin: admin.accountHash,
+    admin,
     fundManager: fundManager.accountHash,
     cep78PackageHash: cep78PackageHash.hash,
     mintFee: mintFee.toString(),
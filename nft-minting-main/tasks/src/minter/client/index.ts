@@ -4,12 +4,16 @@ import {
   CLByteArray,
   CLPublicKey,
   CLPublicKeyTag,
+  CLValue,
   CLValueBuilder,
   Keys,
   RuntimeArgs,
   CLKey,
   CLBool,
+  CLU64Type,
+  CLU256Type,
 } from 'casper-js-sdk';
+import { None, Option, Some } from 'ts-results';
 import { BigNumber, BigNumberish } from '@ethersproject/bignumber';
 
 import {
@@ -18,10 +22,90 @@ import {
   FreeMintArgs,
   NativeMintArgs,
   SetWhitelistArgs,
+  SetWhitelistEntriesArgs,
   SetConfigArgs,
+  TokenMintArgs,
+  SetPaymentTokenArgs,
+  SetMetadataArgs,
+  SetSalePhasesArgs,
+  SetDutchAuctionArgs,
+  SetTieredPricingArgs,
+  SetLinearPricingArgs,
+  SetRevenueSplitsArgs,
+  WithdrawArgs,
+  SweepRebatesArgs,
+  PauseArgs,
+  AirdropArgs,
+  MerkleClaimArgs,
+  Role,
+  RoleArgs,
 } from './types';
 import { MINTER_CONTRACT_WASM, MINT_SESSION_WASM, GAS } from './utils';
-import { accHashToKey, hashToKey } from '../../utils/input';
+import {
+  Phase,
+  Pricing,
+  WhitelistEntry,
+  activePhase,
+  decodePricing,
+  decodeSalePhases,
+  decodeWhitelistEntry,
+  isValidEntry,
+  pricingCost,
+} from './pricing';
+import { accHashToKey, accountOrHashToKey, hashToKey } from '../../utils/input';
+
+const ROLE_DICTS: Record<Role, string> = {
+  [Role.ADMIN]: 'role_admin_dict',
+  [Role.CONFIG_MANAGER]: 'role_config_manager_dict',
+  [Role.WHITELIST_MANAGER]: 'role_whitelist_manager_dict',
+  [Role.FREE_MINTER]: 'role_free_minter_dict',
+  [Role.TREASURER]: 'role_treasurer_dict',
+  [Role.GUARDIAN]: 'role_guardian_dict',
+};
+
+function optionalU64(value?: BigNumberish) {
+  return value === undefined
+    ? CLValueBuilder.option(None, new CLU64Type())
+    : CLValueBuilder.option(Some(CLValueBuilder.u64(value)));
+}
+
+function optionalU256(value?: BigNumberish) {
+  return value === undefined
+    ? CLValueBuilder.option(None, new CLU256Type())
+    : CLValueBuilder.option(Some(CLValueBuilder.u256(value)));
+}
+
+// Optional entry point arguments are passed as options, session code ones as plain values.
+function some(value: CLValue) {
+  return CLValueBuilder.option(Some(value));
+}
+
+function plain(value: CLValue) {
+  return value;
+}
+
+function insertMerkleClaim(
+  runtimeArgs: RuntimeArgs,
+  args: MerkleClaimArgs,
+  optional: (value: CLValue) => CLValue
+) {
+  if (args.merkleProof) {
+    runtimeArgs.insert(
+      'merkle_proof',
+      optional(
+        CLValueBuilder.list(
+          args.merkleProof.map((node) =>
+            CLValueBuilder.byteArray(Uint8Array.from(Buffer.from(node, 'hex')))
+          )
+        )
+      )
+    );
+  }
+
+  if (args.merkleAllowance !== undefined) {
+    runtimeArgs.insert('merkle_allowance', optional(CLValueBuilder.u64(args.merkleAllowance)));
+  }
+}
 
 export class MinterClient {
   private casperClient: CasperClient;
@@ -43,16 +127,28 @@ export class MinterClient {
   public install(args: InstallArg, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
     const runtimeArgs = RuntimeArgs.fromMap({
       admin: accHashToKey(args.adminAccountHash),
-      fund_manager: accHashToKey(args.fundManagerAccountHash),
+      fund_manager: accountOrHashToKey(args.fundManagerAccountHash),
       cep78_package_hash: hashToKey(args.cep78PackageHash),
       mint_fee: CLValueBuilder.u256(args.mintFee),
       only_whitelist: CLValueBuilder.bool(args.onlyWhitelist),
       allow_mint: CLValueBuilder.bool(args.allowMint),
       max_mint: CLValueBuilder.u64(args.max_mint),
+      max_supply: CLValueBuilder.u64(args.maxSupply),
+      collection_name: CLValueBuilder.string(args.collectionName),
+      collection_symbol: CLValueBuilder.string(args.collectionSymbol),
+      base_token_uri: CLValueBuilder.string(args.baseTokenUri),
       name: CLValueBuilder.string('BAYC'),
       disable_old: CLValueBuilder.bool(false),
     });
 
+    if (args.tokenUriSuffix) {
+      runtimeArgs.insert('token_uri_suffix', CLValueBuilder.string(args.tokenUriSuffix));
+    }
+
+    if (args.locked) {
+      runtimeArgs.insert('locked', CLValueBuilder.bool(args.locked));
+    }
+
     return this.contractClient.install(
       MINTER_CONTRACT_WASM,
       runtimeArgs,
@@ -79,31 +175,54 @@ export class MinterClient {
     );
   }
 
+  public finalize(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      name: CLValueBuilder.string('BAYC'),
+      finalize: CLValueBuilder.bool(true),
+    });
+
+    return this.contractClient.install(
+      MINTER_CONTRACT_WASM,
+      runtimeArgs,
+      GAS.INSTALL,
+      deploySender,
+      this.networkName,
+      keys
+    );
+  }
+
   public setConfig(args: SetConfigArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
     const runtimeArgs = RuntimeArgs.fromMap({});
 
-    if (args.adminAccountHash) {
-      runtimeArgs.insert('admin', accHashToKey(args.adminAccountHash));
+    if (args.fundManagerAccountHash) {
+      runtimeArgs.insert('fund_manager', some(accountOrHashToKey(args.fundManagerAccountHash)));
     }
 
-    if (args.fundManagerAccountHash) {
-      runtimeArgs.insert('fund_manager', accHashToKey(args.adminAccountHash));
+    if (args.mintFee !== undefined) {
+      runtimeArgs.insert('mint_fee', some(CLValueBuilder.u256(args.mintFee)));
+    }
+
+    if (args.onlyWhitelist !== undefined) {
+      runtimeArgs.insert('only_whitelist', some(CLValueBuilder.bool(args.onlyWhitelist)));
     }
 
-    if (args.mintFee) {
-      runtimeArgs.insert('mint_fee', CLValueBuilder.u256(args.mintFee));
+    if (args.allowMint !== undefined) {
+      runtimeArgs.insert('allow_mint', some(CLValueBuilder.bool(args.allowMint)));
     }
 
-    if (args.onlyWhitelist) {
-      runtimeArgs.insert('only_whitelist', CLValueBuilder.bool(args.onlyWhitelist));
+    if (args.maxMint !== undefined) {
+      runtimeArgs.insert('max_mint', some(CLValueBuilder.u64(args.maxMint)));
     }
 
-    if (args.allowMint) {
-      runtimeArgs.insert('allow_mint', CLValueBuilder.bool(args.allowMint));
+    if (args.useTreasury !== undefined) {
+      runtimeArgs.insert('use_treasury', some(CLValueBuilder.bool(args.useTreasury)));
     }
 
-    if (args.maxMint) {
-      runtimeArgs.insert('max_mint', CLValueBuilder.u64(args.maxMint));
+    if (args.receiveEntryPoint) {
+      runtimeArgs.insert(
+        'receive_entry_point',
+        some(CLValueBuilder.string(args.receiveEntryPoint))
+      );
     }
 
     return this.contractClient.callEntrypoint(
@@ -116,9 +235,348 @@ export class MinterClient {
     );
   }
 
+  public setRole(
+    entryPoint: 'grant_role' | 'revoke_role',
+    args: RoleArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      role: CLValueBuilder.u8(args.role),
+      account: accHashToKey(args.accountHash),
+    });
+
+    return this.contractClient.callEntrypoint(
+      entryPoint,
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_ROLE,
+      keys
+    );
+  }
+
+  public renounceRole(role: Role, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      role: CLValueBuilder.u8(role),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'renounce_role',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_ROLE,
+      keys
+    );
+  }
+
+  public transferAdmin(
+    newAdminAccountHash: string,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      new_admin: accHashToKey(newAdminAccountHash),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'transfer_admin',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.TRANSFER_ADMIN,
+      keys
+    );
+  }
+
+  public adminTransfer(
+    entryPoint: 'accept_admin' | 'cancel_admin_transfer',
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    return this.contractClient.callEntrypoint(
+      entryPoint,
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.TRANSFER_ADMIN,
+      keys
+    );
+  }
+
+  public setMetadata(args: SetMetadataArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({});
+
+    if (args.collectionName) {
+      runtimeArgs.insert('collection_name', some(CLValueBuilder.string(args.collectionName)));
+    }
+
+    if (args.collectionSymbol) {
+      runtimeArgs.insert('collection_symbol', some(CLValueBuilder.string(args.collectionSymbol)));
+    }
+
+    if (args.baseTokenUri) {
+      runtimeArgs.insert('base_token_uri', some(CLValueBuilder.string(args.baseTokenUri)));
+    }
+
+    if (args.tokenUriSuffix !== undefined) {
+      runtimeArgs.insert('token_uri_suffix', some(CLValueBuilder.string(args.tokenUriSuffix)));
+    }
+
+    return this.contractClient.callEntrypoint(
+      'set_metadata',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_METADATA,
+      keys
+    );
+  }
+
+  public freezeMetadata(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    return this.contractClient.callEntrypoint(
+      'freeze_metadata',
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.SET_METADATA,
+      keys
+    );
+  }
+
+  public setSalePhases(
+    args: SetSalePhasesArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      phase_start_times: CLValueBuilder.list(
+        args.phases.map(({ startTime }) => CLValueBuilder.u64(startTime))
+      ),
+      phase_end_times: CLValueBuilder.list(
+        args.phases.map(({ endTime }) => CLValueBuilder.u64(endTime))
+      ),
+      phase_mint_fees: CLValueBuilder.list(
+        args.phases.map(({ mintFee }) => CLValueBuilder.u256(mintFee))
+      ),
+      phase_max_mints: CLValueBuilder.list(
+        args.phases.map(({ maxMint }) => CLValueBuilder.u64(maxMint))
+      ),
+      phase_only_whitelists: CLValueBuilder.list(
+        args.phases.map(({ onlyWhitelist }) => CLValueBuilder.bool(onlyWhitelist))
+      ),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_sale_phases',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_SALE_PHASES,
+      keys
+    );
+  }
+
+  public setDutchAuction(
+    args: SetDutchAuctionArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      auction_start_time: CLValueBuilder.u64(args.startTime),
+      auction_start_price: CLValueBuilder.u256(args.startPrice),
+      auction_floor_price: CLValueBuilder.u256(args.floorPrice),
+      auction_price_step: CLValueBuilder.u256(args.priceStep),
+      auction_step_interval: CLValueBuilder.u64(args.stepInterval),
+      auction_rebate: CLValueBuilder.bool(args.rebate),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_dutch_auction',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_PRICING,
+      keys
+    );
+  }
+
+  public endAuction(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    return this.contractClient.callEntrypoint(
+      'end_auction',
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.SET_PRICING,
+      keys
+    );
+  }
+
+  public setFixedPricing(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    return this.contractClient.callEntrypoint(
+      'set_fixed_pricing',
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.SET_PRICING,
+      keys
+    );
+  }
+
+  public setTieredPricing(
+    args: SetTieredPricingArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      tier_supplies: CLValueBuilder.list(
+        args.tiers.map(({ supply }) => CLValueBuilder.u64(supply))
+      ),
+      tier_prices: CLValueBuilder.list(args.tiers.map(({ price }) => CLValueBuilder.u256(price))),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_tiered_pricing',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_PRICING,
+      keys
+    );
+  }
+
+  public setLinearPricing(
+    args: SetLinearPricingArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      base_price: CLValueBuilder.u256(args.basePrice),
+      price_increment: CLValueBuilder.u256(args.priceIncrement),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_linear_pricing',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_PRICING,
+      keys
+    );
+  }
+
+  public setRevenueSplits(
+    args: SetRevenueSplitsArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      split_payees: CLValueBuilder.list(
+        args.splits.map(({ payeeAccountHash }) => accountOrHashToKey(payeeAccountHash))
+      ),
+      split_shares: CLValueBuilder.list(args.splits.map(({ share }) => CLValueBuilder.u32(share))),
+    });
+
+    if (args.remainderPayeeAccountHash) {
+      runtimeArgs.insert(
+        'remainder_payee',
+        some(accountOrHashToKey(args.remainderPayeeAccountHash))
+      );
+    }
+
+    return this.contractClient.callEntrypoint(
+      'set_revenue_splits',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_REVENUE_SPLITS,
+      keys
+    );
+  }
+
+  public withdraw(args: WithdrawArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      recipient: accountOrHashToKey(args.recipientAccountHash),
+    });
+
+    if (args.amount) {
+      runtimeArgs.insert('amount', some(CLValueBuilder.u512(args.amount)));
+    }
+
+    return this.contractClient.callEntrypoint(
+      'withdraw',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.WITHDRAW,
+      keys
+    );
+  }
+
+  public pause(args: PauseArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      reason: CLValueBuilder.u32(args.reason),
+    });
+
+    if (args.until) {
+      runtimeArgs.insert('until', some(CLValueBuilder.u64(args.until)));
+    }
+
+    return this.contractClient.callEntrypoint(
+      'pause',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.PAUSE,
+      keys
+    );
+  }
+
+  public unpause(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    return this.contractClient.callEntrypoint(
+      'unpause',
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.PAUSE,
+      keys
+    );
+  }
+
+  public claimRebate(deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    return this.contractClient.callEntrypoint(
+      'claim_rebate',
+      RuntimeArgs.fromMap({}),
+      deploySender,
+      this.networkName,
+      GAS.CLAIM_REBATE,
+      keys
+    );
+  }
+
+  public sweepRebates(
+    args: SweepRebatesArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      recipient: accountOrHashToKey(args.recipientAccountHash),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'sweep_rebates',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SWEEP_REBATES,
+      keys
+    );
+  }
+
   public freeMint(args: FreeMintArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
     const runtimeArgs = RuntimeArgs.fromMap({
-      nft_owner: accHashToKey(args.nftOwnerAccountHash),
+      nft_owner: accountOrHashToKey(args.nftOwnerAccountHash),
       count: CLValueBuilder.u64(args.nftCount),
     });
 
@@ -132,14 +590,36 @@ export class MinterClient {
     );
   }
 
+  public airdrop(args: AirdropArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      airdrop_recipients: CLValueBuilder.list(
+        args.drops.map(({ nftOwnerAccountHash }) => accountOrHashToKey(nftOwnerAccountHash))
+      ),
+      airdrop_counts: CLValueBuilder.list(
+        args.drops.map(({ nftCount }) => CLValueBuilder.u64(nftCount))
+      ),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'airdrop',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.AIRDROP,
+      keys
+    );
+  }
+
   public nativeMint(args: NativeMintArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
     const runtimeArgs = RuntimeArgs.fromMap({
-      nft_owner: accHashToKey(args.nftOwnerAccountHash),
+      nft_owner: accountOrHashToKey(args.nftOwnerAccountHash),
       count: CLValueBuilder.u64(args.nftCount),
       minter_package_hash: hashToKey(args.minterPackageHash),
       amount: CLValueBuilder.u512(args.amount),
     });
 
+    insertMerkleClaim(runtimeArgs, args, plain);
+
     return this.contractClient.install(
       MINT_SESSION_WASM,
       runtimeArgs,
@@ -150,6 +630,88 @@ export class MinterClient {
     );
   }
 
+  public tokenMint(args: TokenMintArgs, deploySender: CLPublicKey, keys: Keys.AsymmetricKey[]) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      nft_owner: accountOrHashToKey(args.nftOwnerAccountHash),
+      count: CLValueBuilder.u64(args.nftCount),
+      payment_token: hashToKey(args.paymentTokenPackageHash),
+    });
+
+    insertMerkleClaim(runtimeArgs, args, some);
+
+    return this.contractClient.callEntrypoint(
+      'token_mint',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.TOKEN_MINT,
+      keys
+    );
+  }
+
+  public setPaymentToken(
+    args: SetPaymentTokenArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      payment_token: hashToKey(args.paymentTokenPackageHash),
+      token_mint_fee: CLValueBuilder.u256(args.tokenMintFee),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_payment_token',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_PAYMENT_TOKEN,
+      keys
+    );
+  }
+
+  public removePaymentToken(
+    paymentTokenPackageHash: string,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      payment_token: hashToKey(paymentTokenPackageHash),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'remove_payment_token',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_PAYMENT_TOKEN,
+      keys
+    );
+  }
+
+  public setMerkleRoot(
+    merkleRoot: string | undefined,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({});
+
+    if (merkleRoot) {
+      runtimeArgs.insert(
+        'merkle_root',
+        some(CLValueBuilder.byteArray(Uint8Array.from(Buffer.from(merkleRoot, 'hex'))))
+      );
+    }
+
+    return this.contractClient.callEntrypoint(
+      'set_merkle_root',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_MERKLE_ROOT,
+      keys
+    );
+  }
+
   public whitelist(
     entryPoint: 'set_whitelist' | 'reset_whitelist',
     args: SetWhitelistArgs,
@@ -183,12 +745,62 @@ export class MinterClient {
     );
   }
 
-  public async admin() {
-    const raw: CLByteArray = await this.contractClient.queryContractData(['admin']);
-    const accountHash = new CLPublicKey(raw.data, CLPublicKeyTag.ED25519).toAccountHashStr();
+  public setWhitelistEntries(
+    args: SetWhitelistEntriesArgs,
+    deploySender: CLPublicKey,
+    keys: Keys.AsymmetricKey[]
+  ) {
+    const runtimeArgs = RuntimeArgs.fromMap({
+      whitelist_accounts: CLValueBuilder.list(
+        args.entries.map(({ accountHash }) => accHashToKey(accountHash))
+      ),
+      whitelist_allowances: CLValueBuilder.list(
+        args.entries.map(({ allowance }) => optionalU64(allowance))
+      ),
+      whitelist_prices: CLValueBuilder.list(args.entries.map(({ price }) => optionalU256(price))),
+      whitelist_expiries: CLValueBuilder.list(args.entries.map(({ expiry }) => optionalU64(expiry))),
+    });
+
+    return this.contractClient.callEntrypoint(
+      'set_whitelist_entries',
+      runtimeArgs,
+      deploySender,
+      this.networkName,
+      GAS.SET_WHITELIST,
+      keys
+    );
+  }
+
+  public async hasRole(role: Role, accountHash: string): Promise<Boolean> {
+    try {
+      const hasRole = await this.contractClient.queryContractDictionary(
+        ROLE_DICTS[role],
+        accountHash.slice(13)
+      );
+      return hasRole.data.val.data;
+    } catch {
+      return false;
+    }
+  }
+
+  // The admin and pending admin are stored as `Option<Key>`, `None` while unset.
+  private async optionalAccount(name: string) {
+    const value: Option<CLKey> = await this.contractClient.queryContractData([name]);
+    const raw = value.some ? value.val : undefined;
+    const accountHash = raw?.isAccount()
+      ? `account-hash-${Buffer.from(raw.data.data).toString('hex')}`
+      : undefined;
     return { accountHash, raw };
   }
 
+  public admin() {
+    return this.optionalAccount('admin');
+  }
+
+  public pendingAdmin() {
+    return this.optionalAccount('pending_admin');
+  }
+
   public async fundManager() {
     const raw: CLByteArray = await this.contractClient.queryContractData(['fund_manager']);
     const accountHash = new CLPublicKey(raw.data, CLPublicKeyTag.ED25519).toAccountHashStr();
@@ -201,14 +813,59 @@ export class MinterClient {
     return { hash, raw };
   }
 
+  public collectionName(): Promise<string> {
+    return this.contractClient.queryContractData(['collection_name']);
+  }
+
+  public collectionSymbol(): Promise<string> {
+    return this.contractClient.queryContractData(['collection_symbol']);
+  }
+
+  public baseTokenUri(): Promise<string> {
+    return this.contractClient.queryContractData(['base_token_uri']);
+  }
+
+  public tokenUriSuffix(): Promise<string> {
+    return this.contractClient.queryContractData(['token_uri_suffix']);
+  }
+
+  public metadataFrozen(): Promise<Boolean> {
+    return this.contractClient.queryContractData(['metadata_frozen']);
+  }
+
+  public upgradeKeyDropped(): Promise<Boolean> {
+    return this.contractClient.queryContractData(['upgrade_key_dropped']);
+  }
+
   public mintFee(): Promise<BigNumber> {
     return this.contractClient.queryContractData(['mint_fee']);
   }
 
+  public async auctionClearingPrice(): Promise<BigNumber | undefined> {
+    const clearingPrice = await this.contractClient.queryContractData(['auction_clearing_price']);
+    return clearingPrice ? BigNumber.from(clearingPrice.toString()) : undefined;
+  }
+
+  public useTreasury(): Promise<Boolean> {
+    return this.contractClient.queryContractData(['use_treasury']);
+  }
+
+  public paused(): Promise<Boolean> {
+    return this.contractClient.queryContractData(['paused']);
+  }
+
+  public pauseReason(): Promise<BigNumber> {
+    return this.contractClient.queryContractData(['pause_reason']);
+  }
+
   public mintCount(): Promise<BigNumber> {
     return this.contractClient.queryContractData(['mint_count']);
   }
 
+  public maxSupply(): Promise<BigNumber> {
+    return this.contractClient.queryContractData(['max_supply']);
+  }
+
   public maxMint(): Promise<BigNumber> {
     return this.contractClient.queryContractData(['max_mint']);
   }
@@ -233,8 +890,120 @@ export class MinterClient {
     }
   }
 
-  public async getMintCost(count: BigNumberish): Promise<BigNumber> {
-    const mintFee = await this.mintFee();
-    return mintFee.mul(count);
+  public async mintedBy(accountHash: string): Promise<BigNumber> {
+    try {
+      const minted = await this.contractClient.queryContractDictionary(
+        'minted_dict',
+        accountHash.slice(13)
+      );
+      return BigNumber.from(minted.data.val.data.toString());
+    } catch {
+      return BigNumber.from(0);
+    }
+  }
+
+  // Reads the serialized value of a query, for the values stored with the `Any` CL type.
+  private async queryBytes(method: string, params: object): Promise<Uint8Array> {
+    const response = await fetch(this.nodeAddress, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify({ jsonrpc: '2.0', id: 1, method, params }),
+    });
+    const { result, error } = await response.json();
+    if (error) {
+      throw new Error(error.message);
+    }
+    return Uint8Array.from(Buffer.from(result.stored_value.CLValue.bytes, 'hex'));
+  }
+
+  private async queryContractBytes(name: string): Promise<Uint8Array> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    return this.queryBytes('query_global_state', {
+      state_identifier: { StateRootHash: stateRootHash },
+      key: this.contractClient.contractHash,
+      path: [name],
+    });
+  }
+
+  private async queryDictionaryBytes(dictionaryName: string, itemKey: string): Promise<Uint8Array> {
+    const stateRootHash = await this.casperClient.nodeClient.getStateRootHash();
+    return this.queryBytes('state_get_dictionary_item', {
+      state_root_hash: stateRootHash,
+      dictionary_identifier: {
+        ContractNamedKey: {
+          key: this.contractClient.contractHash,
+          dictionary_name: dictionaryName,
+          dictionary_item_key: itemKey,
+        },
+      },
+    });
+  }
+
+  public async salePhases(): Promise<Phase[]> {
+    return decodeSalePhases(await this.queryContractBytes('sale_phases'));
+  }
+
+  public async pricing(): Promise<Pricing> {
+    return decodePricing(await this.queryContractBytes('pricing'));
+  }
+
+  public async whitelistEntry(accountHash: string): Promise<WhitelistEntry | undefined> {
+    try {
+      const bytes = await this.queryDictionaryBytes(
+        'whitelist_entries_dict',
+        accountHash.slice(13)
+      );
+      return decodeWhitelistEntry(bytes);
+    } catch {
+      return undefined;
+    }
+  }
+
+  // Mirrors the `get_mint_cost` entry point at the current time, with the price of the account
+  // whitelist entry when given. A Dutch auction price only drops until the deploy runs, but
+  // tiered and linear prices rise with every NFT minted before it, so fund the mint session
+  // with a margin: it refunds whatever the minter doesn't charge.
+  public async getMintCost(count: BigNumberish, accountHash?: string): Promise<BigNumber> {
+    const blockTime = BigNumber.from(Date.now());
+    const [phases, pricing, mintFee, maxMint, onlyWhitelist, mintCount, entry] = await Promise.all([
+      this.salePhases(),
+      this.pricing(),
+      this.mintFee(),
+      this.maxMint(),
+      this.onlyWhitelist(),
+      this.mintCount(),
+      accountHash ? this.whitelistEntry(accountHash) : Promise.resolve(undefined),
+    ]);
+    const phase = activePhase(
+      phases,
+      {
+        startTime: BigNumber.from(0),
+        endTime: BigNumber.from('0xffffffffffffffff'),
+        mintFee: BigNumber.from(mintFee.toString()),
+        maxMint: BigNumber.from(maxMint.toString()),
+        onlyWhitelist: Boolean(onlyWhitelist),
+      },
+      blockTime
+    );
+    if (entry?.price && isValidEntry(entry, blockTime)) {
+      return entry.price.mul(count);
+    }
+    return pricingCost(pricing, phase.mintFee, blockTime, mintCount.toString(), count);
+  }
+
+  public async tokenMintFee(paymentTokenPackageHash: string): Promise<BigNumber> {
+    const tokenMintFee = await this.contractClient.queryContractDictionary(
+      'payment_tokens_dict',
+      paymentTokenPackageHash.slice(5)
+    );
+    return BigNumber.from(tokenMintFee.data.val.data.toString());
+  }
+
+  public async getTokenMintCost(
+    paymentTokenPackageHash: string,
+    count: BigNumberish
+  ): Promise<BigNumber> {
+    const tokenMintFee = await this.tokenMintFee(paymentTokenPackageHash);
+    return tokenMintFee.mul(count);
   }
 }
@@ -0,0 +1,197 @@
+import { BigNumber, BigNumberish } from '@ethersproject/bignumber';
+
+export type Phase = {
+  startTime: BigNumber;
+  endTime: BigNumber;
+  mintFee: BigNumber;
+  maxMint: BigNumber;
+  onlyWhitelist: boolean;
+};
+
+export type PriceTier = {
+  supply: BigNumber;
+  price: BigNumber;
+};
+
+export type Pricing =
+  | { kind: 'fixed' }
+  | {
+      kind: 'dutch_auction';
+      startTime: BigNumber;
+      startPrice: BigNumber;
+      floorPrice: BigNumber;
+      priceStep: BigNumber;
+      stepInterval: BigNumber;
+      rebate: boolean;
+    }
+  | { kind: 'tiered'; tiers: PriceTier[] }
+  | { kind: 'linear'; basePrice: BigNumber; priceIncrement: BigNumber };
+
+export type WhitelistEntry = {
+  allowance?: BigNumber;
+  price?: BigNumber;
+  expiry?: BigNumber;
+};
+
+// Reads the bytesrepr encoding of the values the minter stores with the `Any` CL type.
+class BytesReader {
+  private offset = 0;
+
+  constructor(private bytes: Uint8Array) {}
+
+  public u8(): number {
+    const value = this.bytes[this.offset];
+    this.offset += 1;
+    return value;
+  }
+
+  public bool(): boolean {
+    return this.u8() === 1;
+  }
+
+  public u32(): number {
+    return this.littleEndian(4).toNumber();
+  }
+
+  public u64(): BigNumber {
+    return this.littleEndian(8);
+  }
+
+  public u256(): BigNumber {
+    return this.littleEndian(this.u8());
+  }
+
+  public option<T>(read: () => T): T | undefined {
+    return this.u8() === 1 ? read() : undefined;
+  }
+
+  public list<T>(read: () => T): T[] {
+    const length = this.u32();
+    const items: T[] = [];
+    for (let i = 0; i < length; i += 1) {
+      items.push(read());
+    }
+    return items;
+  }
+
+  private littleEndian(length: number): BigNumber {
+    const bytes = Buffer.from(this.bytes.slice(this.offset, this.offset + length)).reverse();
+    this.offset += length;
+    return BigNumber.from(`0x${bytes.toString('hex') || '0'}`);
+  }
+}
+
+export function decodeSalePhases(bytes: Uint8Array): Phase[] {
+  const reader = new BytesReader(bytes);
+  return reader.list(() => ({
+    startTime: reader.u64(),
+    endTime: reader.u64(),
+    mintFee: reader.u256(),
+    maxMint: reader.u64(),
+    onlyWhitelist: reader.bool(),
+  }));
+}
+
+export function decodePricing(bytes: Uint8Array): Pricing {
+  const reader = new BytesReader(bytes);
+  const tag = reader.u8();
+  switch (tag) {
+    case 0:
+      return { kind: 'fixed' };
+    case 1:
+      return {
+        kind: 'dutch_auction',
+        startTime: reader.u64(),
+        startPrice: reader.u256(),
+        floorPrice: reader.u256(),
+        priceStep: reader.u256(),
+        stepInterval: reader.u64(),
+        rebate: reader.bool(),
+      };
+    case 2:
+      return {
+        kind: 'tiered',
+        tiers: reader.list(() => ({ supply: reader.u64(), price: reader.u256() })),
+      };
+    case 3:
+      return { kind: 'linear', basePrice: reader.u256(), priceIncrement: reader.u256() };
+    default:
+      throw new Error(`Unknown pricing tag ${tag}`);
+  }
+}
+
+// Whitelist entries are stored in their dictionary as an `Option`, `None` once removed.
+export function decodeWhitelistEntry(bytes: Uint8Array): WhitelistEntry | undefined {
+  const reader = new BytesReader(bytes);
+  return reader.option(() => ({
+    allowance: reader.option(() => reader.u64()),
+    price: reader.option(() => reader.u256()),
+    expiry: reader.option(() => reader.u64()),
+  }));
+}
+
+export function isValidEntry(entry: WhitelistEntry, blockTime: BigNumber): boolean {
+  return entry.expiry === undefined || blockTime.lt(entry.expiry);
+}
+
+// Mirrors `MINTER::get_active_phase`, the global terms apply without a sale schedule.
+export function activePhase(phases: Phase[], globalPhase: Phase, blockTime: BigNumber): Phase {
+  if (phases.length === 0) {
+    return globalPhase;
+  }
+  const phase = phases.find(
+    ({ startTime, endTime }) => startTime.lte(blockTime) && blockTime.lt(endTime)
+  );
+  if (!phase) {
+    throw new Error('No active sale phase');
+  }
+  return phase;
+}
+
+function dutchAuctionPrice(
+  auction: Extract<Pricing, { kind: 'dutch_auction' }>,
+  blockTime: BigNumber
+): BigNumber {
+  const elapsed = blockTime.gt(auction.startTime) ? blockTime.sub(auction.startTime) : 0;
+  const discount = auction.priceStep.mul(BigNumber.from(elapsed).div(auction.stepInterval));
+  const price = auction.startPrice.gt(discount) ? auction.startPrice.sub(discount) : 0;
+  return BigNumber.from(price).gt(auction.floorPrice) ? BigNumber.from(price) : auction.floorPrice;
+}
+
+// Mirrors `Pricing::cost`: the CSPR cost of minting `count` NFTs after `sold` were minted.
+export function pricingCost(
+  pricing: Pricing,
+  mintFee: BigNumber,
+  blockTime: BigNumber,
+  sold: BigNumberish,
+  count: BigNumberish
+): BigNumber {
+  const start = BigNumber.from(sold);
+  const units = BigNumber.from(count);
+  switch (pricing.kind) {
+    case 'fixed':
+      return mintFee.mul(units);
+    case 'dutch_auction':
+      return dutchAuctionPrice(pricing, blockTime).mul(units);
+    case 'tiered': {
+      const end = start.add(units);
+      let index = start;
+      let cost = BigNumber.from(0);
+      pricing.tiers.forEach(({ supply, price }) => {
+        if (index.lt(end) && index.lt(supply)) {
+          const tierEnd = end.lt(supply) ? end : supply;
+          cost = cost.add(price.mul(tierEnd.sub(index)));
+          index = tierEnd;
+        }
+      });
+      const lastTier = pricing.tiers[pricing.tiers.length - 1];
+      return lastTier ? cost.add(lastTier.price.mul(end.sub(index))) : cost;
+    }
+    case 'linear': {
+      const indexes = units.mul(start).add(units.mul(units.isZero() ? 0 : units.sub(1)).div(2));
+      return pricing.basePrice.mul(units).add(pricing.priceIncrement.mul(indexes));
+    }
+    default:
+      throw new Error('Unknown pricing');
+  }
+}
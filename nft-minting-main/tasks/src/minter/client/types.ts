@@ -8,6 +8,12 @@ export type InstallArg = {
   onlyWhitelist: boolean;
   allowMint: boolean;
   max_mint: BigNumberish;
+  maxSupply: BigNumberish;
+  collectionName: string;
+  collectionSymbol: string;
+  baseTokenUri: string;
+  tokenUriSuffix?: string;
+  locked?: boolean;
 };
 
 export type UpgradeArg = {
@@ -15,12 +21,102 @@ export type UpgradeArg = {
 };
 
 export type SetConfigArgs = {
-  adminAccountHash?: string;
   fundManagerAccountHash?: string;
   mintFee?: BigNumberish;
   onlyWhitelist?: boolean;
   allowMint?: boolean;
   maxMint?: BigNumberish;
+  useTreasury?: boolean;
+  receiveEntryPoint?: string;
+};
+
+export type WithdrawArgs = {
+  recipientAccountHash: string;
+  amount?: BigNumberish;
+};
+
+export type SweepRebatesArgs = {
+  recipientAccountHash: string;
+};
+
+export type SetMetadataArgs = {
+  collectionName?: string;
+  collectionSymbol?: string;
+  baseTokenUri?: string;
+  tokenUriSuffix?: string;
+};
+
+export type SalePhase = {
+  startTime: BigNumberish;
+  endTime: BigNumberish;
+  mintFee: BigNumberish;
+  maxMint: BigNumberish;
+  onlyWhitelist: boolean;
+};
+
+export type SetSalePhasesArgs = {
+  phases: Array<SalePhase>;
+};
+
+export type SetDutchAuctionArgs = {
+  startTime: BigNumberish;
+  startPrice: BigNumberish;
+  floorPrice: BigNumberish;
+  priceStep: BigNumberish;
+  stepInterval: BigNumberish;
+  rebate: boolean;
+};
+
+export type PriceTier = {
+  supply: BigNumberish;
+  price: BigNumberish;
+};
+
+export type SetTieredPricingArgs = {
+  tiers: Array<PriceTier>;
+};
+
+export type SetLinearPricingArgs = {
+  basePrice: BigNumberish;
+  priceIncrement: BigNumberish;
+};
+
+export type RevenueSplit = {
+  payeeAccountHash: string;
+  share: number;
+};
+
+export type SetRevenueSplitsArgs = {
+  splits: Array<RevenueSplit>;
+  remainderPayeeAccountHash?: string;
+};
+
+export enum Role {
+  ADMIN = 0,
+  CONFIG_MANAGER = 1,
+  WHITELIST_MANAGER = 2,
+  FREE_MINTER = 3,
+  TREASURER = 4,
+  GUARDIAN = 5,
+}
+
+export type Airdrop = {
+  nftOwnerAccountHash: string;
+  nftCount: BigNumberish;
+};
+
+export type AirdropArgs = {
+  drops: Array<Airdrop>;
+};
+
+export type PauseArgs = {
+  reason: number;
+  until?: BigNumberish;
+};
+
+export type RoleArgs = {
+  role: Role;
+  accountHash: string;
 };
 
 export type FreeMintArgs = {
@@ -28,11 +124,38 @@ export type FreeMintArgs = {
   nftCount: BigNumberish;
 };
 
-export type NativeMintArgs = FreeMintArgs & {
-  minterPackageHash: string;
-  amount: BigNumberish;
+export type MerkleClaimArgs = {
+  merkleProof?: Array<string>;
+  merkleAllowance?: BigNumberish;
 };
 
+export type NativeMintArgs = FreeMintArgs &
+  MerkleClaimArgs & {
+    minterPackageHash: string;
+    amount: BigNumberish;
+  };
+
 export type SetWhitelistArgs = {
   users: Array<{ accountHash: string; value: boolean }>;
 };
+
+export type WhitelistEntry = {
+  accountHash: string;
+  allowance?: BigNumberish;
+  price?: BigNumberish;
+  expiry?: BigNumberish;
+};
+
+export type SetWhitelistEntriesArgs = {
+  entries: Array<WhitelistEntry>;
+};
+
+export type TokenMintArgs = FreeMintArgs &
+  MerkleClaimArgs & {
+    paymentTokenPackageHash: string;
+  };
+
+export type SetPaymentTokenArgs = {
+  paymentTokenPackageHash: string;
+  tokenMintFee: BigNumberish;
+};
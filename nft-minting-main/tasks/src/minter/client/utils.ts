@@ -21,7 +21,21 @@ function toMotes(csprs: number) {
 export const GAS = {
   INSTALL: toMotes(140),
   FREE_MINT: toMotes(10000),
+  AIRDROP: toMotes(10000),
   NATIVE_MINT: toMotes(85),
   SET_WHITELIST: toMotes(2),
   SET_CONFIG: toMotes(20),
+  TOKEN_MINT: toMotes(85),
+  SET_PAYMENT_TOKEN: toMotes(2),
+  SET_METADATA: toMotes(2),
+  SET_SALE_PHASES: toMotes(2),
+  SET_PRICING: toMotes(5),
+  CLAIM_REBATE: toMotes(5),
+  SWEEP_REBATES: toMotes(5),
+  SET_REVENUE_SPLITS: toMotes(2),
+  WITHDRAW: toMotes(5),
+  PAUSE: toMotes(2),
+  SET_MERKLE_ROOT: toMotes(2),
+  SET_ROLE: toMotes(2),
+  TRANSFER_ADMIN: toMotes(2),
 };
@@ -16,6 +16,10 @@ export async function installMinterContract() {
       onlyWhitelist: true,
       allowMint: true,
       max_mint: 20,
+      maxSupply: 10000,
+      collectionName: 'BoredApeYachtClub',
+      collectionSymbol: 'BAYC',
+      baseTokenUri: 'ipfs://QmeSjSinHpPnmXmspMjwiXyN6zS4E9zccariGR3jxcaWtq/',
     },
     AdminKeypair.publicKey,
     [AdminKeypair]
@@ -19,3 +19,7 @@ export function hashToKey(hash: string): CLKey {
   const hashBytes = hashToUnit8Array(HashType.CONTRACT, hash);
   return CLValueBuilder.key(new CLByteArray(hashBytes));
 }
+
+export function accountOrHashToKey(hash: string): CLKey {
+  return hash.startsWith('account-hash-') ? accHashToKey(hash) : hashToKey(hash);
+}
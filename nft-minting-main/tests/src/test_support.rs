@@ -0,0 +1,958 @@
+//! Installs the bundled CEP-78 collection and the minter into an `InMemoryWasmTestBuilder`,
+//! and wraps every minter entry point in a typed helper.
+//!
+//! Deploys cannot observe the values returned by stored contracts, so the read helpers query
+//! the named keys and dictionaries the minter getters read instead.
+
+// Not every helper is used by the current tests.
+#![allow(dead_code)]
+
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT, DEFAULT_ACCOUNT_ADDR,
+    MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{engine_state::Error as EngineStateError, execution};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{FromBytes, ToBytes},
+    runtime_args, ApiError, CLType, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey,
+    RuntimeArgs, SecretKey, U256, U512,
+};
+
+pub const MINTER_WASM: &str = "contract.wasm";
+/// The minter built from the first release, before schema versioning.
+pub const FIRST_RELEASE_MINTER_WASM: &str = "minter_first_release.wasm";
+pub const CEP78_WASM: &str = "cep78.wasm";
+pub const MINT_SESSION_WASM: &str = "public_mint_call.wasm";
+
+pub const MINTER_NAME: &str = "test";
+pub const COLLECTION_NAME: &str = "TestCollection";
+pub const COLLECTION_SYMBOL: &str = "TEST";
+pub const BASE_TOKEN_URI: &str = "https://example.com/";
+pub const TOKEN_URI_SUFFIX: &str = ".json";
+
+const CEP78_MINTING_MODE_ACL: u8 = 2;
+const CEP78_OWNERSHIP_MODE_TRANSFERABLE: u8 = 2;
+const CEP78_NFT_KIND_DIGITAL: u8 = 1;
+const CEP78_HOLDER_MODE_MIXED: u8 = 2;
+const CEP78_WHITELIST_MODE_UNLOCKED: u8 = 0;
+const CEP78_METADATA_KIND_NFT721: u8 = 1;
+const CEP78_IDENTIFIER_MODE_ORDINAL: u8 = 0;
+const CEP78_METADATA_MUTABILITY_IMMUTABLE: u8 = 0;
+const CEP78_BURN_MODE_NON_BURNABLE: u8 = 1;
+const CEP78_REVERSE_LOOKUP_MODE_COMPLETE: u8 = 1;
+const CEP78_EVENTS_MODE_CES: u8 = 2;
+const CEP78_TOTAL_TOKEN_SUPPLY: u64 = 1_000;
+
+const NAMED_KEY_LAST_MINT_CHARGED: &str = "minter_last_mint_charged";
+
+/// The minter errors, mirroring `contract/src/error.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Error {
+    MintLimitExceed = 1005,
+    MintNotAllowed = 1006,
+    NotEnoughBalance = 1010,
+    NotWhitelisted = 1011,
+    PermissionDenied = 1012,
+    WrongArguments = 1014,
+    PaymentTokenNotAccepted = 1015,
+    MetadataFrozen = 1016,
+    NoActivePhase = 1017,
+    InvalidPhase = 1018,
+    SupplyExceeded = 1019,
+    InvalidRole = 1021,
+    NoPendingAdmin = 1022,
+    Paused = 1030,
+    NotPaused = 1031,
+    UpgradeKeyDropped = 1034,
+    RebateClaimExpired = 1035,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> ApiError {
+        ApiError::User(error as u16)
+    }
+}
+
+/// The minter roles, mirroring `contract/src/role.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Role {
+    Admin = 0,
+    ConfigManager = 1,
+    WhitelistManager = 2,
+    FreeMinter = 3,
+    Treasurer = 4,
+    Guardian = 5,
+}
+
+impl Role {
+    fn dict_name(&self) -> &'static str {
+        match self {
+            Role::Admin => "role_admin_dict",
+            Role::ConfigManager => "role_config_manager_dict",
+            Role::WhitelistManager => "role_whitelist_manager_dict",
+            Role::FreeMinter => "role_free_minter_dict",
+            Role::Treasurer => "role_treasurer_dict",
+            Role::Guardian => "role_guardian_dict",
+        }
+    }
+}
+
+/// The install arguments of the minter.
+#[derive(Debug, Clone)]
+pub struct InstallArgs {
+    pub mint_fee: U256,
+    pub only_whitelist: bool,
+    pub allow_mint: bool,
+    pub max_mint: u64,
+    pub max_supply: u64,
+    pub locked: bool,
+}
+
+impl Default for InstallArgs {
+    fn default() -> Self {
+        InstallArgs {
+            mint_fee: U256::from(10_000_000_000u64),
+            only_whitelist: false,
+            allow_mint: true,
+            max_mint: 3,
+            max_supply: 10,
+            locked: false,
+        }
+    }
+}
+
+/// The optional arguments of `set_config`.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigUpdate {
+    pub fund_manager: Option<Key>,
+    pub mint_fee: Option<U256>,
+    pub only_whitelist: Option<bool>,
+    pub allow_mint: Option<bool>,
+    pub max_mint: Option<u64>,
+    pub use_treasury: Option<bool>,
+    pub receive_entry_point: Option<String>,
+}
+
+/// The optional arguments of `set_metadata`.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataUpdate {
+    pub collection_name: Option<String>,
+    pub collection_symbol: Option<String>,
+    pub base_token_uri: Option<String>,
+    pub token_uri_suffix: Option<String>,
+}
+
+/// A whitelist entry passed to `set_whitelist_entries`.
+#[derive(Debug, Clone, Default)]
+pub struct WhitelistEntry {
+    pub allowance: Option<u64>,
+    pub price: Option<U256>,
+    pub expiry: Option<u64>,
+}
+
+/// A sale phase passed to `set_sale_phases`.
+#[derive(Debug, Clone)]
+pub struct Phase {
+    pub start_time: u64,
+    pub end_time: u64,
+    pub mint_fee: U256,
+    pub max_mint: u64,
+    pub only_whitelist: bool,
+}
+
+/// The arguments of `set_dutch_auction`.
+#[derive(Debug, Clone)]
+pub struct DutchAuction {
+    pub start_time: u64,
+    pub start_price: U256,
+    pub floor_price: U256,
+    pub price_step: U256,
+    pub step_interval: u64,
+    pub rebate: bool,
+}
+
+pub struct TestContext {
+    pub builder: InMemoryWasmTestBuilder,
+    /// Installs both contracts and holds every minter role.
+    pub admin: AccountHash,
+    pub fund_manager: AccountHash,
+    pub user_1: AccountHash,
+    pub user_2: AccountHash,
+    pub cep78_package_hash: ContractPackageHash,
+    pub minter_package_hash: ContractPackageHash,
+    /// The block time of the next deploys.
+    pub block_time: u64,
+}
+
+impl TestContext {
+    pub fn new() -> Self {
+        TestContext::install(InstallArgs::default())
+    }
+
+    /// Installs CEP-78 in ACL minting mode, installs the minter with the given arguments and
+    /// adds the minter package to the CEP-78 ACL whitelist.
+    pub fn install(args: InstallArgs) -> Self {
+        TestContext::install_minter(MINTER_WASM, |admin, fund_manager, cep78_package_hash| {
+            runtime_args! {
+                "name" => MINTER_NAME.to_string(),
+                "disable_old" => false,
+                "admin" => Key::Account(admin),
+                "fund_manager" => Key::Account(fund_manager),
+                "cep78_package_hash" => Key::Hash(cep78_package_hash.value()),
+                "mint_fee" => args.mint_fee,
+                "only_whitelist" => args.only_whitelist,
+                "allow_mint" => args.allow_mint,
+                "max_mint" => args.max_mint,
+                "max_supply" => args.max_supply,
+                "collection_name" => COLLECTION_NAME.to_string(),
+                "collection_symbol" => COLLECTION_SYMBOL.to_string(),
+                "base_token_uri" => BASE_TOKEN_URI.to_string(),
+                "token_uri_suffix" => TOKEN_URI_SUFFIX.to_string(),
+                "locked" => args.locked,
+            }
+        })
+    }
+
+    /// Installs the minter of the first release instead, which has no supply cap, metadata
+    /// or lock arguments.
+    pub fn install_first_release(args: InstallArgs) -> Self {
+        TestContext::install_minter(
+            FIRST_RELEASE_MINTER_WASM,
+            |admin, fund_manager, cep78_package_hash| {
+                runtime_args! {
+                    "name" => MINTER_NAME.to_string(),
+                    "disable_old" => false,
+                    "admin" => Key::Account(admin),
+                    "fund_manager" => Key::Account(fund_manager),
+                    "cep78_package_hash" => Key::Hash(cep78_package_hash.value()),
+                    "mint_fee" => args.mint_fee,
+                    "only_whitelist" => args.only_whitelist,
+                    "allow_mint" => args.allow_mint,
+                    "max_mint" => args.max_mint,
+                }
+            },
+        )
+    }
+
+    fn install_minter(
+        minter_wasm: &str,
+        minter_args: impl FnOnce(AccountHash, AccountHash, ContractPackageHash) -> RuntimeArgs,
+    ) -> Self {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+            .commit();
+
+        let admin = *DEFAULT_ACCOUNT_ADDR;
+        let fund_manager = create_account(&mut builder, 1);
+        let user_1 = create_account(&mut builder, 2);
+        let user_2 = create_account(&mut builder, 3);
+
+        let cep78_args = runtime_args! {
+            "collection_name" => COLLECTION_NAME.to_string(),
+            "collection_symbol" => COLLECTION_SYMBOL.to_string(),
+            "total_token_supply" => CEP78_TOTAL_TOKEN_SUPPLY,
+            "minting_mode" => CEP78_MINTING_MODE_ACL,
+            "ownership_mode" => CEP78_OWNERSHIP_MODE_TRANSFERABLE,
+            "nft_kind" => CEP78_NFT_KIND_DIGITAL,
+            "holder_mode" => CEP78_HOLDER_MODE_MIXED,
+            "whitelist_mode" => CEP78_WHITELIST_MODE_UNLOCKED,
+            "nft_metadata_kind" => CEP78_METADATA_KIND_NFT721,
+            "identifier_mode" => CEP78_IDENTIFIER_MODE_ORDINAL,
+            "metadata_mutability" => CEP78_METADATA_MUTABILITY_IMMUTABLE,
+            "burn_mode" => CEP78_BURN_MODE_NON_BURNABLE,
+            "owner_reverse_lookup_mode" => CEP78_REVERSE_LOOKUP_MODE_COMPLETE,
+            "events_mode" => CEP78_EVENTS_MODE_CES,
+            "json_schema" => String::new(),
+        };
+        let request = ExecuteRequestBuilder::standard(admin, CEP78_WASM, cep78_args).build();
+        builder.exec(request).expect_success().commit();
+        let cep78_package_hash = account_named_key(
+            &builder,
+            admin,
+            &format!("cep78_contract_package_{COLLECTION_NAME}"),
+        )
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap();
+
+        let minter_args = minter_args(admin, fund_manager, cep78_package_hash);
+        let request = ExecuteRequestBuilder::standard(admin, minter_wasm, minter_args).build();
+        builder.exec(request).expect_success().commit();
+        let minter_package_hash = account_named_key(
+            &builder,
+            admin,
+            &format!("minter_contract_package_hash_{MINTER_NAME}"),
+        )
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap();
+
+        let mut context = TestContext {
+            builder,
+            admin,
+            fund_manager,
+            user_1,
+            user_2,
+            cep78_package_hash,
+            minter_package_hash,
+            block_time: 0,
+        };
+        context.allow_minting();
+        context
+    }
+
+    /// Sets the CEP-78 ACL whitelist to the minter package and its current contract version,
+    /// which CEP-78 checks when the minter mints. It has to be called again after upgrades.
+    pub fn allow_minting(&mut self) {
+        let request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            self.admin,
+            self.cep78_package_hash,
+            None,
+            "set_variables",
+            runtime_args! {
+                "acl_whitelist" => vec![
+                    Key::Hash(self.minter_package_hash.value()),
+                    Key::Hash(self.minter_contract_hash().value()),
+                ],
+            },
+        );
+        self.exec(request).unwrap();
+    }
+
+    /// Runs the deploy and returns the error it reverted with.
+    pub fn exec(&mut self, request: ExecuteRequestBuilder) -> Result<(), ApiError> {
+        let request = request.with_block_time(self.block_time).build();
+        self.builder.exec(request).commit();
+        match self.builder.get_error() {
+            None => Ok(()),
+            Some(EngineStateError::Exec(execution::Error::Revert(error))) => Err(error),
+            Some(error) => panic!("deploy failed without reverting: {error:?}"),
+        }
+    }
+
+    /// Calls the entry point of the latest minter version.
+    pub fn call(
+        &mut self,
+        sender: AccountHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), ApiError> {
+        let request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            sender,
+            self.minter_package_hash,
+            None,
+            entry_point,
+            args,
+        );
+        self.exec(request)
+    }
+
+    /// Calls the entry point of a given minter version and returns whether the deploy
+    /// succeeded, which also fails when the version is disabled.
+    pub fn call_contract(
+        &mut self,
+        sender: AccountHash,
+        contract_hash: ContractHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> bool {
+        let request =
+            ExecuteRequestBuilder::contract_call_by_hash(sender, contract_hash, entry_point, args)
+                .with_block_time(self.block_time)
+                .build();
+        self.builder.exec(request).commit();
+        self.builder.get_error().is_none()
+    }
+
+    /// Runs the minter wasm again under the same name, which adds a contract version.
+    pub fn upgrade(&mut self, sender: AccountHash, disable_old: bool) -> Result<(), ApiError> {
+        let request = ExecuteRequestBuilder::standard(
+            sender,
+            MINTER_WASM,
+            runtime_args! {
+                "name" => MINTER_NAME.to_string(),
+                "disable_old" => disable_old,
+            },
+        );
+        self.exec(request)
+    }
+
+    pub fn finalize(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        let request = ExecuteRequestBuilder::standard(
+            sender,
+            MINTER_WASM,
+            runtime_args! {
+                "name" => MINTER_NAME.to_string(),
+                "finalize" => true,
+            },
+        );
+        self.exec(request)
+    }
+
+    pub fn set_config(
+        &mut self,
+        sender: AccountHash,
+        update: ConfigUpdate,
+    ) -> Result<(), ApiError> {
+        let mut args = RuntimeArgs::new();
+        insert_optional(&mut args, "fund_manager", update.fund_manager);
+        insert_optional(&mut args, "mint_fee", update.mint_fee);
+        insert_optional(&mut args, "only_whitelist", update.only_whitelist);
+        insert_optional(&mut args, "allow_mint", update.allow_mint);
+        insert_optional(&mut args, "max_mint", update.max_mint);
+        insert_optional(&mut args, "use_treasury", update.use_treasury);
+        insert_optional(&mut args, "receive_entry_point", update.receive_entry_point);
+        self.call(sender, "set_config", args)
+    }
+
+    pub fn grant_role(
+        &mut self,
+        sender: AccountHash,
+        role: Role,
+        account: Key,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! { "role" => role as u8, "account" => account };
+        self.call(sender, "grant_role", args)
+    }
+
+    pub fn revoke_role(
+        &mut self,
+        sender: AccountHash,
+        role: Role,
+        account: Key,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! { "role" => role as u8, "account" => account };
+        self.call(sender, "revoke_role", args)
+    }
+
+    pub fn renounce_role(&mut self, sender: AccountHash, role: Role) -> Result<(), ApiError> {
+        self.call(
+            sender,
+            "renounce_role",
+            runtime_args! { "role" => role as u8 },
+        )
+    }
+
+    pub fn transfer_admin(&mut self, sender: AccountHash, new_admin: Key) -> Result<(), ApiError> {
+        self.call(
+            sender,
+            "transfer_admin",
+            runtime_args! { "new_admin" => new_admin },
+        )
+    }
+
+    pub fn accept_admin(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "accept_admin", RuntimeArgs::new())
+    }
+
+    pub fn cancel_admin_transfer(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "cancel_admin_transfer", RuntimeArgs::new())
+    }
+
+    pub fn set_metadata(
+        &mut self,
+        sender: AccountHash,
+        update: MetadataUpdate,
+    ) -> Result<(), ApiError> {
+        let mut args = RuntimeArgs::new();
+        insert_optional(&mut args, "collection_name", update.collection_name);
+        insert_optional(&mut args, "collection_symbol", update.collection_symbol);
+        insert_optional(&mut args, "base_token_uri", update.base_token_uri);
+        insert_optional(&mut args, "token_uri_suffix", update.token_uri_suffix);
+        self.call(sender, "set_metadata", args)
+    }
+
+    pub fn freeze_metadata(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "freeze_metadata", RuntimeArgs::new())
+    }
+
+    pub fn free_mint(
+        &mut self,
+        sender: AccountHash,
+        nft_owner: Key,
+        count: u64,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! { "nft_owner" => nft_owner, "count" => count };
+        self.call(sender, "free_mint", args)
+    }
+
+    pub fn airdrop(&mut self, sender: AccountHash, drops: &[(Key, u64)]) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "airdrop_recipients" => drops.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+            "airdrop_counts" => drops.iter().map(|(_, count)| *count).collect::<Vec<_>>(),
+        };
+        self.call(sender, "airdrop", args)
+    }
+
+    /// Mints through the mint session, which funds the source purse with `amount`, refunds
+    /// what is left and returns what the minter charged.
+    pub fn native_mint(
+        &mut self,
+        sender: AccountHash,
+        nft_owner: Key,
+        count: u64,
+        amount: U512,
+    ) -> Result<U512, ApiError> {
+        let args = runtime_args! {
+            "nft_owner" => nft_owner,
+            "count" => count,
+            ARG_AMOUNT => amount,
+            "minter_package_hash" => Key::Hash(self.minter_package_hash.value()),
+        };
+        self.exec(ExecuteRequestBuilder::standard(
+            sender,
+            MINT_SESSION_WASM,
+            args,
+        ))?;
+        Ok(self.account_value(sender, NAMED_KEY_LAST_MINT_CHARGED))
+    }
+
+    pub fn set_whitelist(
+        &mut self,
+        sender: AccountHash,
+        values: &[(Key, bool)],
+    ) -> Result<(), ApiError> {
+        self.call(sender, "set_whitelist", whitelist_args(values))
+    }
+
+    pub fn reset_whitelist(
+        &mut self,
+        sender: AccountHash,
+        values: &[(Key, bool)],
+    ) -> Result<(), ApiError> {
+        self.call(sender, "reset_whitelist", whitelist_args(values))
+    }
+
+    pub fn set_whitelist_entries(
+        &mut self,
+        sender: AccountHash,
+        entries: &[(Key, WhitelistEntry)],
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "whitelist_accounts" => entries.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+            "whitelist_allowances" =>
+                entries.iter().map(|(_, entry)| entry.allowance).collect::<Vec<_>>(),
+            "whitelist_prices" => entries.iter().map(|(_, entry)| entry.price).collect::<Vec<_>>(),
+            "whitelist_expiries" =>
+                entries.iter().map(|(_, entry)| entry.expiry).collect::<Vec<_>>(),
+        };
+        self.call(sender, "set_whitelist_entries", args)
+    }
+
+    pub fn set_merkle_root(
+        &mut self,
+        sender: AccountHash,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<(), ApiError> {
+        let mut args = RuntimeArgs::new();
+        insert_optional(&mut args, "merkle_root", merkle_root);
+        self.call(sender, "set_merkle_root", args)
+    }
+
+    pub fn set_sale_phases(
+        &mut self,
+        sender: AccountHash,
+        phases: &[Phase],
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "phase_start_times" => phases.iter().map(|phase| phase.start_time).collect::<Vec<_>>(),
+            "phase_end_times" => phases.iter().map(|phase| phase.end_time).collect::<Vec<_>>(),
+            "phase_mint_fees" => phases.iter().map(|phase| phase.mint_fee).collect::<Vec<_>>(),
+            "phase_max_mints" => phases.iter().map(|phase| phase.max_mint).collect::<Vec<_>>(),
+            "phase_only_whitelists" =>
+                phases.iter().map(|phase| phase.only_whitelist).collect::<Vec<_>>(),
+        };
+        self.call(sender, "set_sale_phases", args)
+    }
+
+    pub fn token_mint(
+        &mut self,
+        sender: AccountHash,
+        nft_owner: Key,
+        count: u64,
+        payment_token: Key,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "nft_owner" => nft_owner,
+            "count" => count,
+            "payment_token" => payment_token,
+        };
+        self.call(sender, "token_mint", args)
+    }
+
+    pub fn set_payment_token(
+        &mut self,
+        sender: AccountHash,
+        payment_token: Key,
+        token_mint_fee: U256,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "payment_token" => payment_token,
+            "token_mint_fee" => token_mint_fee,
+        };
+        self.call(sender, "set_payment_token", args)
+    }
+
+    pub fn remove_payment_token(
+        &mut self,
+        sender: AccountHash,
+        payment_token: Key,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! { "payment_token" => payment_token };
+        self.call(sender, "remove_payment_token", args)
+    }
+
+    pub fn set_dutch_auction(
+        &mut self,
+        sender: AccountHash,
+        auction: DutchAuction,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "auction_start_time" => auction.start_time,
+            "auction_start_price" => auction.start_price,
+            "auction_floor_price" => auction.floor_price,
+            "auction_price_step" => auction.price_step,
+            "auction_step_interval" => auction.step_interval,
+            "auction_rebate" => auction.rebate,
+        };
+        self.call(sender, "set_dutch_auction", args)
+    }
+
+    pub fn end_auction(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "end_auction", RuntimeArgs::new())
+    }
+
+    pub fn claim_rebate(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "claim_rebate", RuntimeArgs::new())
+    }
+
+    pub fn set_fixed_pricing(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "set_fixed_pricing", RuntimeArgs::new())
+    }
+
+    pub fn set_tiered_pricing(
+        &mut self,
+        sender: AccountHash,
+        tiers: &[(u64, U256)],
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "tier_supplies" => tiers.iter().map(|(supply, _)| *supply).collect::<Vec<_>>(),
+            "tier_prices" => tiers.iter().map(|(_, price)| *price).collect::<Vec<_>>(),
+        };
+        self.call(sender, "set_tiered_pricing", args)
+    }
+
+    pub fn set_linear_pricing(
+        &mut self,
+        sender: AccountHash,
+        base_price: U256,
+        price_increment: U256,
+    ) -> Result<(), ApiError> {
+        let args = runtime_args! {
+            "base_price" => base_price,
+            "price_increment" => price_increment,
+        };
+        self.call(sender, "set_linear_pricing", args)
+    }
+
+    pub fn set_revenue_splits(
+        &mut self,
+        sender: AccountHash,
+        splits: &[(Key, u32)],
+        remainder_payee: Option<Key>,
+    ) -> Result<(), ApiError> {
+        let mut args = runtime_args! {
+            "split_payees" => splits.iter().map(|(payee, _)| *payee).collect::<Vec<_>>(),
+            "split_shares" => splits.iter().map(|(_, share)| *share).collect::<Vec<_>>(),
+        };
+        insert_optional(&mut args, "remainder_payee", remainder_payee);
+        self.call(sender, "set_revenue_splits", args)
+    }
+
+    pub fn withdraw(
+        &mut self,
+        sender: AccountHash,
+        recipient: Key,
+        amount: Option<U512>,
+    ) -> Result<(), ApiError> {
+        let mut args = runtime_args! { "recipient" => recipient };
+        insert_optional(&mut args, ARG_AMOUNT, amount);
+        self.call(sender, "withdraw", args)
+    }
+
+    pub fn pause(
+        &mut self,
+        sender: AccountHash,
+        reason: u32,
+        until: Option<u64>,
+    ) -> Result<(), ApiError> {
+        let mut args = runtime_args! { "reason" => reason };
+        insert_optional(&mut args, "until", until);
+        self.call(sender, "pause", args)
+    }
+
+    pub fn unpause(&mut self, sender: AccountHash) -> Result<(), ApiError> {
+        self.call(sender, "unpause", RuntimeArgs::new())
+    }
+
+    /// Calls one of the read-only entry points, which only proves it does not revert.
+    pub fn query_entry_point(
+        &mut self,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), ApiError> {
+        let admin = self.admin;
+        self.call(admin, entry_point, args)
+    }
+
+    pub fn minter_contract_hash(&self) -> ContractHash {
+        account_named_key(
+            &self.builder,
+            self.admin,
+            &format!("minter_contract_hash_{MINTER_NAME}"),
+        )
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap()
+    }
+
+    pub fn minter_contract_version(&self) -> u32 {
+        self.account_value(
+            self.admin,
+            &format!("minter_contract_version_{MINTER_NAME}"),
+        )
+    }
+
+    /// Reads a value stored under a named key of the current minter version.
+    pub fn minter_value<T: CLTyped + FromBytes>(&self, name: &str) -> T {
+        let contract_hash = self.minter_contract_hash();
+        query(&self.builder, Key::Hash(contract_hash.value()), name)
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        self.minter_value("schema_version")
+    }
+
+    pub fn migrated_version(&self) -> u32 {
+        self.minter_value("migrated_contract_version")
+    }
+
+    pub fn is_upgrade_key_dropped(&self) -> bool {
+        self.minter_value("upgrade_key_dropped")
+    }
+
+    /// Whether the admin account still holds the access URef of the minter package.
+    pub fn has_package_access(&self) -> bool {
+        self.builder
+            .get_expected_account(self.admin)
+            .named_keys()
+            .contains_key(&format!("minter_contract_access_{MINTER_NAME}"))
+    }
+
+    /// The number of URefs granting access to a user group of the minter package.
+    pub fn package_group_urefs(&self) -> usize {
+        self.builder
+            .get_contract_package(self.minter_package_hash)
+            .expect("should have minter package")
+            .groups()
+            .values()
+            .map(|urefs| urefs.len())
+            .sum()
+    }
+
+    pub fn mint_count(&self) -> u64 {
+        self.minter_value("mint_count")
+    }
+
+    pub fn remaining_supply(&self) -> u64 {
+        self.minter_value::<u64>("max_supply") - self.mint_count()
+    }
+
+    pub fn mint_fee(&self) -> U256 {
+        self.minter_value("mint_fee")
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.minter_value("paused")
+    }
+
+    pub fn pending_admin(&self) -> Option<Key> {
+        self.minter_value("pending_admin")
+    }
+
+    /// Returns the parameters an entry point of the current minter version declares.
+    pub fn entry_point_parameters(&self, name: &str) -> Vec<(String, CLType)> {
+        let contract = self
+            .builder
+            .get_contract(self.minter_contract_hash())
+            .unwrap();
+        contract
+            .entry_point(name)
+            .unwrap_or_else(|| panic!("missing entry point {name}"))
+            .args()
+            .iter()
+            .map(|parameter| (parameter.name().to_string(), parameter.cl_type().clone()))
+            .collect()
+    }
+
+    pub fn treasury_balance(&self) -> U512 {
+        let contract = self
+            .builder
+            .get_contract(self.minter_contract_hash())
+            .unwrap();
+        let purse = contract
+            .named_keys()
+            .get("treasury_purse")
+            .unwrap()
+            .into_uref()
+            .unwrap();
+        self.builder.get_purse_balance(purse)
+    }
+
+    pub fn minted_by(&self, account: Key) -> u64 {
+        self.minter_dictionary("minted_dict", &account)
+            .unwrap_or_default()
+    }
+
+    pub fn has_role(&self, role: Role, account: Key) -> bool {
+        self.minter_dictionary(role.dict_name(), &account)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the account is in the plain whitelist.
+    pub fn is_whitelisted(&self, account: Key) -> bool {
+        self.minter_dictionary("whitelist_dict", &account)
+            .unwrap_or_default()
+    }
+
+    pub fn cep78_minted(&self) -> u64 {
+        let contract_hash = self.cep78_contract_hash();
+        query(
+            &self.builder,
+            Key::Hash(contract_hash.value()),
+            "number_of_minted_tokens",
+        )
+    }
+
+    pub fn cep78_owner_of(&self, token_id: u64) -> Option<Key> {
+        self.cep78_dictionary("token_owners", &token_id.to_string())
+    }
+
+    pub fn cep78_metadata_of(&self, token_id: u64) -> Option<String> {
+        self.cep78_dictionary("metadata_nft721", &token_id.to_string())
+    }
+
+    /// Returns the CSPR balance of the main purse of the account.
+    pub fn balance_of(&self, account: AccountHash) -> U512 {
+        let main_purse = self.builder.get_expected_account(account).main_purse();
+        self.builder.get_purse_balance(main_purse)
+    }
+
+    fn cep78_contract_hash(&self) -> ContractHash {
+        account_named_key(
+            &self.builder,
+            self.admin,
+            &format!("cep78_contract_hash_{COLLECTION_NAME}"),
+        )
+        .into_hash()
+        .map(ContractHash::new)
+        .unwrap()
+    }
+
+    fn account_value<T: CLTyped + FromBytes>(&self, account: AccountHash, name: &str) -> T {
+        query(&self.builder, Key::Account(account), name)
+    }
+
+    /// Reads a minter dictionary, where values are stored as `Option<T>` under the key hash.
+    fn minter_dictionary<T: CLTyped + FromBytes>(&self, dict_name: &str, key: &Key) -> Option<T> {
+        let contract_hash = self.minter_contract_hash();
+        dictionary(&self.builder, contract_hash, dict_name, &key_to_str(key))
+            .and_then(|value: Option<T>| value)
+    }
+
+    fn cep78_dictionary<T: CLTyped + FromBytes>(&self, dict_name: &str, key: &str) -> Option<T> {
+        dictionary(&self.builder, self.cep78_contract_hash(), dict_name, key)
+    }
+}
+
+impl Default for TestContext {
+    fn default() -> Self {
+        TestContext::new()
+    }
+}
+
+fn create_account(builder: &mut InMemoryWasmTestBuilder, seed: u8) -> AccountHash {
+    let secret_key = SecretKey::ed25519_from_bytes([seed; 32]).unwrap();
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+    let request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(MINIMUM_ACCOUNT_CREATION_BALANCE),
+            "target" => account_hash,
+            "id" => Option::<u64>::None,
+        },
+    )
+    .build();
+    builder.exec(request).expect_success().commit();
+    account_hash
+}
+
+fn account_named_key(builder: &InMemoryWasmTestBuilder, account: AccountHash, name: &str) -> Key {
+    *builder
+        .get_expected_account(account)
+        .named_keys()
+        .get(name)
+        .unwrap_or_else(|| panic!("missing named key {name}"))
+}
+
+fn query<T: CLTyped + FromBytes>(builder: &InMemoryWasmTestBuilder, base: Key, name: &str) -> T {
+    builder
+        .query(None, base, &[name.to_string()])
+        .unwrap_or_else(|error| panic!("cannot query {name}: {error}"))
+        .as_cl_value()
+        .cloned()
+        .unwrap()
+        .into_t()
+        .unwrap()
+}
+
+fn dictionary<T: CLTyped + FromBytes>(
+    builder: &InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    dict_name: &str,
+    key: &str,
+) -> Option<T> {
+    let contract = builder.get_contract(contract_hash).unwrap();
+    let seed_uref = contract.named_keys().get(dict_name)?.into_uref()?;
+    builder
+        .query_dictionary_item(None, seed_uref, key)
+        .ok()
+        .map(|value| value.as_cl_value().cloned().unwrap().into_t().unwrap())
+}
+
+/// Mirrors `key_to_str` of `contract-utils`.
+fn key_to_str(key: &Key) -> String {
+    match key {
+        Key::Account(account) => account.to_string(),
+        Key::Hash(package) => hex::encode(package),
+        _ => panic!("unexpected key variant {key}"),
+    }
+}
+
+fn whitelist_args(values: &[(Key, bool)]) -> RuntimeArgs {
+    runtime_args! {
+        "whitelist_accounts" => values.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+        "whitelist_values" => values.iter().map(|(_, value)| *value).collect::<Vec<_>>(),
+    }
+}
+
+/// Optional entry point arguments are left out when `None`, and passed as `Option<T>` otherwise.
+fn insert_optional<T: CLTyped + ToBytes>(args: &mut RuntimeArgs, name: &str, value: Option<T>) {
+    if value.is_some() {
+        args.insert(name, value).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+use casper_types::{ApiError, Key, U512};
+
+use crate::test_support::{ConfigUpdate, Error, InstallArgs, TestContext};
+
+fn mint_fee() -> U512 {
+    U512::from(InstallArgs::default().mint_fee.as_u128())
+}
+
+#[test]
+fn accounts_cannot_mint_more_than_max_mint() {
+    let mut context = TestContext::new();
+    let user = context.user_1;
+    let max_mint = InstallArgs::default().max_mint;
+
+    context
+        .native_mint(user, Key::Account(user), max_mint, mint_fee() * max_mint)
+        .unwrap();
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee());
+
+    assert_eq!(result, Err(ApiError::from(Error::MintLimitExceed)));
+    assert_eq!(context.minted_by(Key::Account(user)), max_mint);
+}
+
+#[test]
+fn config_manager_raises_max_mint() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    let update = ConfigUpdate {
+        max_mint: Some(4),
+        ..Default::default()
+    };
+    context.set_config(admin, update).unwrap();
+
+    context
+        .native_mint(user, Key::Account(user), 4, mint_fee() * 4)
+        .unwrap();
+}
+
+#[test]
+fn mints_stay_within_max_supply() {
+    let mut context = TestContext::install(InstallArgs {
+        max_supply: 4,
+        ..Default::default()
+    });
+    let (admin, user) = (context.admin, context.user_1);
+
+    context.free_mint(admin, Key::Account(admin), 3).unwrap();
+    assert_eq!(context.remaining_supply(), 1);
+    let result = context.native_mint(user, Key::Account(user), 2, mint_fee() * 2);
+    assert_eq!(result, Err(ApiError::from(Error::SupplyExceeded)));
+    let result = context.free_mint(admin, Key::Account(user), 2);
+    assert_eq!(result, Err(ApiError::from(Error::SupplyExceeded)));
+
+    context
+        .native_mint(user, Key::Account(user), 1, mint_fee())
+        .unwrap();
+    assert_eq!(context.remaining_supply(), 0);
+    assert_eq!(context.cep78_minted(), 4);
+}
+
+#[test]
+fn airdrops_fit_in_the_remaining_supply_or_mint_nothing() {
+    let mut context = TestContext::install(InstallArgs {
+        max_supply: 4,
+        ..Default::default()
+    });
+    let (admin, user_1, user_2) = (context.admin, context.user_1, context.user_2);
+
+    let result = context.airdrop(
+        admin,
+        &[(Key::Account(user_1), 2), (Key::Account(user_2), 3)],
+    );
+    assert_eq!(result, Err(ApiError::from(Error::SupplyExceeded)));
+    assert_eq!(context.cep78_minted(), 0);
+
+    context
+        .airdrop(
+            admin,
+            &[(Key::Account(user_1), 2), (Key::Account(user_2), 2)],
+        )
+        .unwrap();
+    assert_eq!(context.cep78_owner_of(1), Some(Key::Account(user_1)));
+    assert_eq!(context.cep78_owner_of(2), Some(Key::Account(user_2)));
+    assert_eq!(context.remaining_supply(), 0);
+}
+
+#[test]
+fn minting_can_be_closed() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    let update = ConfigUpdate {
+        allow_mint: Some(false),
+        ..Default::default()
+    };
+    context.set_config(admin, update).unwrap();
+
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee());
+
+    assert_eq!(result, Err(ApiError::from(Error::MintNotAllowed)));
+}
@@ -0,0 +1,53 @@
+use casper_types::{CLType, CLTyped, Key, U256};
+
+use crate::test_support::{ConfigUpdate, TestContext};
+
+fn option_of<T: CLTyped>() -> CLType {
+    CLType::Option(Box::new(T::cl_type()))
+}
+
+#[test]
+fn constructor_declares_every_install_argument() {
+    let context = TestContext::new();
+
+    let parameters = context.entry_point_parameters("constructor");
+
+    assert!(parameters.contains(&("max_mint".to_string(), CLType::U64)));
+    assert!(parameters.contains(&("max_supply".to_string(), CLType::U64)));
+    assert_eq!(parameters.len(), 13);
+}
+
+#[test]
+fn optional_arguments_are_declared_as_options() {
+    let context = TestContext::new();
+
+    let parameters = context.entry_point_parameters("set_config");
+
+    assert!(parameters.contains(&("fund_manager".to_string(), option_of::<Key>())));
+    assert!(parameters.contains(&("mint_fee".to_string(), option_of::<U256>())));
+    assert!(parameters.contains(&("max_mint".to_string(), option_of::<u64>())));
+    let parameters = context.entry_point_parameters("set_whitelist_entries");
+    assert!(parameters.contains(&(
+        "whitelist_prices".to_string(),
+        CLType::List(Box::new(option_of::<U256>()))
+    )));
+}
+
+#[test]
+fn optional_arguments_can_be_left_out_or_passed() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+
+    context
+        .set_config(
+            admin,
+            ConfigUpdate {
+                max_mint: Some(5),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    context.set_config(admin, ConfigUpdate::default()).unwrap();
+
+    assert_eq!(context.minter_value::<u64>("max_mint"), 5);
+}
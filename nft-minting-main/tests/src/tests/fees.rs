@@ -0,0 +1,86 @@
+use casper_types::{ApiError, Key, U256, U512};
+
+use crate::test_support::{ConfigUpdate, Error, InstallArgs, TestContext};
+
+fn mint_fee() -> U512 {
+    U512::from(InstallArgs::default().mint_fee.as_u128())
+}
+
+#[test]
+fn native_mint_pays_the_mint_fee_to_the_fund_manager() {
+    let mut context = TestContext::new();
+    let (user, fund_manager) = (context.user_1, context.fund_manager);
+    let fund_manager_balance = context.balance_of(fund_manager);
+
+    let charged = context
+        .native_mint(user, Key::Account(user), 2, mint_fee() * 3)
+        .unwrap();
+
+    assert_eq!(charged, mint_fee() * 2);
+    assert_eq!(
+        context.balance_of(fund_manager),
+        fund_manager_balance + mint_fee() * 2
+    );
+    assert_eq!(context.cep78_owner_of(0), Some(Key::Account(user)));
+    assert_eq!(context.cep78_owner_of(1), Some(Key::Account(user)));
+    assert_eq!(context.minted_by(Key::Account(user)), 2);
+}
+
+#[test]
+fn native_mint_requires_the_whole_fee() {
+    let mut context = TestContext::new();
+    let user = context.user_1;
+
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee() - 1);
+
+    assert_eq!(result, Err(ApiError::from(Error::NotEnoughBalance)));
+    assert_eq!(context.mint_count(), 0);
+}
+
+#[test]
+fn config_manager_updates_the_mint_fee() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    let update = ConfigUpdate {
+        mint_fee: Some(U256::from(1_000_000_000u64)),
+        ..Default::default()
+    };
+
+    let result = context.set_config(user, update.clone());
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    context.set_config(admin, update).unwrap();
+
+    assert_eq!(context.mint_fee(), U256::from(1_000_000_000u64));
+    let charged = context
+        .native_mint(user, Key::Account(user), 1, mint_fee())
+        .unwrap();
+    assert_eq!(charged, U512::from(1_000_000_000u64));
+}
+
+#[test]
+fn treasury_holds_the_proceeds_until_withdrawn() {
+    let mut context = TestContext::new();
+    let (admin, user_1, user_2) = (context.admin, context.user_1, context.user_2);
+    let fund_manager_balance = context.balance_of(context.fund_manager);
+    let update = ConfigUpdate {
+        use_treasury: Some(true),
+        ..Default::default()
+    };
+    context.set_config(admin, update).unwrap();
+
+    context
+        .native_mint(user_1, Key::Account(user_1), 1, mint_fee())
+        .unwrap();
+    assert_eq!(context.treasury_balance(), mint_fee());
+    assert_eq!(
+        context.balance_of(context.fund_manager),
+        fund_manager_balance
+    );
+
+    let result = context.withdraw(user_1, Key::Account(user_1), None);
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    let recipient_balance = context.balance_of(user_2);
+    context.withdraw(admin, Key::Account(user_2), None).unwrap();
+    assert!(context.treasury_balance().is_zero());
+    assert_eq!(context.balance_of(user_2), recipient_balance + mint_fee());
+}
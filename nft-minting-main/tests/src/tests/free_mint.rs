@@ -0,0 +1,64 @@
+use casper_types::{ApiError, Key};
+
+use crate::test_support::{Error, Role, TestContext, BASE_TOKEN_URI, TOKEN_URI_SUFFIX};
+
+#[test]
+fn free_mint_requires_the_free_minter_role() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+
+    let result = context.free_mint(user, Key::Account(user), 1);
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+
+    context
+        .grant_role(admin, Role::FreeMinter, Key::Account(user))
+        .unwrap();
+    assert!(context.has_role(Role::FreeMinter, Key::Account(user)));
+    context.free_mint(user, Key::Account(user), 1).unwrap();
+
+    context
+        .revoke_role(admin, Role::FreeMinter, Key::Account(user))
+        .unwrap();
+    let result = context.free_mint(user, Key::Account(user), 1);
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    assert_eq!(context.mint_count(), 1);
+}
+
+#[test]
+fn only_admins_grant_roles() {
+    let mut context = TestContext::new();
+    let (user_1, user_2) = (context.user_1, context.user_2);
+
+    let result = context.grant_role(user_1, Role::FreeMinter, Key::Account(user_2));
+
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    assert!(!context.has_role(Role::FreeMinter, Key::Account(user_2)));
+}
+
+#[test]
+fn free_mint_builds_the_token_metadata() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+
+    context.free_mint(admin, Key::Account(user), 2).unwrap();
+
+    assert_eq!(context.cep78_owner_of(1), Some(Key::Account(user)));
+    let metadata = context.cep78_metadata_of(1).unwrap();
+    let token_uri = format!("{BASE_TOKEN_URI}1{TOKEN_URI_SUFFIX}");
+    assert!(metadata.contains(&token_uri), "{metadata}");
+    assert_eq!(context.minted_by(Key::Account(user)), 2);
+}
+
+#[test]
+fn free_mint_is_blocked_while_paused() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    context.pause(admin, 1, None).unwrap();
+    assert!(context.is_paused());
+
+    let result = context.free_mint(admin, Key::Account(user), 1);
+    assert_eq!(result, Err(ApiError::from(Error::Paused)));
+
+    context.unpause(admin).unwrap();
+    context.free_mint(admin, Key::Account(user), 1).unwrap();
+}
@@ -0,0 +1,53 @@
+use casper_types::RuntimeArgs;
+
+use crate::test_support::{Error, InstallArgs, TestContext};
+
+#[test]
+fn locked_install_cannot_be_upgraded() {
+    let mut context = TestContext::install(InstallArgs {
+        locked: true,
+        ..Default::default()
+    });
+    let admin = context.admin;
+    assert!(context.is_upgrade_key_dropped());
+    assert!(!context.has_package_access());
+
+    let result = context.upgrade(admin, false);
+
+    assert_eq!(result, Err(Error::UpgradeKeyDropped.into()));
+    assert_eq!(context.minter_contract_version(), 1);
+}
+
+#[test]
+fn finalize_drops_the_upgrade_key() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    context.upgrade(admin, true).unwrap();
+    assert!(!context.is_upgrade_key_dropped());
+
+    context.finalize(admin).unwrap();
+
+    assert!(context.is_upgrade_key_dropped());
+    assert!(!context.has_package_access());
+    assert_eq!(context.package_group_urefs(), 0);
+    assert_eq!(
+        context.upgrade(admin, false),
+        Err(Error::UpgradeKeyDropped.into())
+    );
+    assert_eq!(
+        context.finalize(admin),
+        Err(Error::UpgradeKeyDropped.into())
+    );
+}
+
+#[test]
+fn finalize_cannot_be_called_outside_the_session_code() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    let contract_hash = context.minter_contract_hash();
+
+    let called = context.call_contract(admin, contract_hash, "finalize", RuntimeArgs::new());
+
+    assert!(!called);
+    assert!(!context.is_upgrade_key_dropped());
+}
@@ -0,0 +1,7 @@
+mod caps;
+mod entry_points;
+mod fees;
+mod free_mint;
+mod lock;
+mod upgrade;
+mod whitelist;
@@ -0,0 +1,147 @@
+use casper_types::{runtime_args, Key, RuntimeArgs, U256, U512};
+
+use crate::test_support::{InstallArgs, Role, TestContext};
+
+#[test]
+fn upgrade_keeps_the_minter_state() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    context.free_mint(admin, Key::Account(user), 2).unwrap();
+    let old_contract_hash = context.minter_contract_hash();
+
+    context.upgrade(admin, false).unwrap();
+
+    assert_ne!(context.minter_contract_hash(), old_contract_hash);
+    assert_eq!(context.minter_contract_version(), 2);
+    assert_eq!(context.mint_count(), 2);
+    assert_eq!(context.minted_by(Key::Account(user)), 2);
+    assert!(context.has_role(Role::Admin, Key::Account(admin)));
+}
+
+#[test]
+fn upgrade_migrates_the_new_version() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    assert_eq!(context.migrated_version(), 1);
+
+    context.upgrade(admin, true).unwrap();
+    context.upgrade(admin, true).unwrap();
+
+    assert_eq!(context.minter_contract_version(), 3);
+    assert_eq!(context.migrated_version(), 3);
+    assert_eq!(context.schema_version(), 1);
+}
+
+#[test]
+fn migrate_cannot_be_called_outside_an_upgrade() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    context.upgrade(admin, false).unwrap();
+    let contract_hash = context.minter_contract_hash();
+
+    let called = context.call_contract(
+        admin,
+        contract_hash,
+        "migrate",
+        runtime_args! { "contract_version" => 3u32 },
+    );
+
+    assert!(!called);
+    assert_eq!(context.migrated_version(), 2);
+}
+
+#[test]
+fn upgraded_minter_mints_once_allowed_by_cep78() {
+    let mut context = TestContext::new();
+    let (admin, user) = (context.admin, context.user_1);
+    context.free_mint(admin, Key::Account(user), 1).unwrap();
+    context.upgrade(admin, true).unwrap();
+
+    assert!(context.free_mint(admin, Key::Account(user), 1).is_err());
+
+    context.allow_minting();
+    context.free_mint(admin, Key::Account(user), 1).unwrap();
+    assert_eq!(context.cep78_owner_of(1), Some(Key::Account(user)));
+    assert_eq!(context.mint_count(), 2);
+}
+
+#[test]
+fn disabled_versions_cannot_be_called() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    let old_contract_hash = context.minter_contract_hash();
+    context.upgrade(admin, true).unwrap();
+
+    let called = context.call_contract(admin, old_contract_hash, "unpause", RuntimeArgs::new());
+
+    assert!(!called);
+}
+
+#[test]
+fn old_versions_stay_callable_unless_disabled() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    let old_contract_hash = context.minter_contract_hash();
+    context.upgrade(admin, false).unwrap();
+
+    let called = context.call_contract(
+        admin,
+        old_contract_hash,
+        "pause",
+        runtime_args! { "reason" => 1u32 },
+    );
+
+    assert!(called);
+    assert!(context.is_paused());
+}
+
+#[test]
+fn constructor_cannot_be_called_after_install() {
+    let mut context = TestContext::new();
+    let admin = context.admin;
+    let contract_hash = context.minter_contract_hash();
+    let args = runtime_args! {
+        "admin" => Key::Account(context.user_1),
+        "fund_manager" => Key::Account(context.user_1),
+        "cep78_package_hash" => Key::Hash(context.cep78_package_hash.value()),
+        "mint_fee" => U256::zero(),
+        "only_whitelist" => false,
+        "allow_mint" => true,
+        "max_mint" => 100u64,
+        "max_supply" => 100u64,
+        "collection_name" => String::new(),
+        "collection_symbol" => String::new(),
+        "base_token_uri" => String::new(),
+        "token_uri_suffix" => String::new(),
+    };
+
+    let called = context.call_contract(admin, contract_hash, "constructor", args);
+
+    assert!(!called);
+    assert!(context.has_role(Role::Admin, Key::Account(admin)));
+    assert!(!context.has_role(Role::Admin, Key::Account(context.user_1)));
+}
+
+#[test]
+fn upgrade_migrates_the_first_release() {
+    let mut context = TestContext::install_first_release(InstallArgs::default());
+    let (admin, user) = (context.admin, context.user_1);
+    context.free_mint(admin, Key::Account(user), 1).unwrap();
+
+    context.upgrade(admin, true).unwrap();
+    context.allow_minting();
+
+    assert_eq!(context.schema_version(), 1);
+    assert_eq!(context.migrated_version(), 2);
+    assert!(!context.is_upgrade_key_dropped());
+    assert!(context.has_role(Role::Admin, Key::Account(admin)));
+    let mint_fee = U512::from(InstallArgs::default().mint_fee.as_u128());
+    let charged = context
+        .native_mint(user, Key::Account(user), 2, mint_fee * 2)
+        .unwrap();
+    assert_eq!(charged, mint_fee * 2);
+    context.free_mint(admin, Key::Account(user), 1).unwrap();
+    assert_eq!(context.mint_count(), 4);
+    assert_eq!(context.cep78_minted(), 4);
+    assert!(context.cep78_metadata_of(3).unwrap().contains("BAYC"));
+}
@@ -0,0 +1,114 @@
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256, U512};
+
+use crate::test_support::{ConfigUpdate, Error, InstallArgs, TestContext, WhitelistEntry};
+
+fn whitelist_only() -> TestContext {
+    TestContext::install(InstallArgs {
+        only_whitelist: true,
+        ..Default::default()
+    })
+}
+
+fn mint_fee() -> U512 {
+    U512::from(InstallArgs::default().mint_fee.as_u128())
+}
+
+#[test]
+fn only_whitelisted_accounts_can_mint() {
+    let mut context = whitelist_only();
+    let (admin, user) = (context.admin, context.user_1);
+
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee());
+    assert_eq!(result, Err(ApiError::from(Error::NotWhitelisted)));
+
+    context
+        .set_whitelist(admin, &[(Key::Account(user), true)])
+        .unwrap();
+    assert!(context.is_whitelisted(Key::Account(user)));
+    context
+        .native_mint(user, Key::Account(user), 1, mint_fee())
+        .unwrap();
+
+    context
+        .set_whitelist(admin, &[(Key::Account(user), false)])
+        .unwrap();
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee());
+    assert_eq!(result, Err(ApiError::from(Error::NotWhitelisted)));
+}
+
+#[test]
+fn whitelist_is_open_when_only_whitelist_is_off() {
+    let mut context = whitelist_only();
+    let (admin, user) = (context.admin, context.user_1);
+    let update = ConfigUpdate {
+        only_whitelist: Some(false),
+        ..Default::default()
+    };
+    context.set_config(admin, update).unwrap();
+
+    context
+        .native_mint(user, Key::Account(user), 1, mint_fee())
+        .unwrap();
+}
+
+#[test]
+fn whitelist_manager_role_is_required() {
+    let mut context = whitelist_only();
+    let user = context.user_1;
+
+    let result = context.set_whitelist(user, &[(Key::Account(user), true)]);
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    let result = context.set_whitelist_entries(user, &[(Key::Account(user), Default::default())]);
+    assert_eq!(result, Err(ApiError::from(Error::PermissionDenied)));
+    assert!(!context.is_whitelisted(Key::Account(user)));
+}
+
+#[test]
+fn whitelist_entries_set_allowance_and_price_until_expiry() {
+    let mut context = whitelist_only();
+    let (admin, user) = (context.admin, context.user_1);
+    let price = U256::from(1_000_000_000u64);
+    let entry = WhitelistEntry {
+        allowance: Some(5),
+        price: Some(price),
+        expiry: Some(1_000),
+    };
+    context
+        .set_whitelist_entries(admin, &[(Key::Account(user), entry)])
+        .unwrap();
+
+    let charged = context
+        .native_mint(user, Key::Account(user), 5, mint_fee())
+        .unwrap();
+    assert_eq!(charged, U512::from(5 * price.as_u64()));
+    let result = context.native_mint(user, Key::Account(user), 1, mint_fee());
+    assert_eq!(result, Err(ApiError::from(Error::MintLimitExceed)));
+
+    let other = context.user_2;
+    let entry = WhitelistEntry {
+        expiry: Some(1_000),
+        ..Default::default()
+    };
+    context
+        .set_whitelist_entries(admin, &[(Key::Account(other), entry)])
+        .unwrap();
+    context.block_time = 1_000;
+    let result = context.native_mint(other, Key::Account(other), 1, mint_fee());
+    assert_eq!(result, Err(ApiError::from(Error::NotWhitelisted)));
+}
+
+#[test]
+fn whitelist_entries_need_one_value_per_account() {
+    let mut context = whitelist_only();
+    let (admin, user) = (context.admin, context.user_1);
+    let args = runtime_args! {
+        "whitelist_accounts" => vec![Key::Account(user)],
+        "whitelist_allowances" => Vec::<Option<u64>>::new(),
+        "whitelist_prices" => vec![Option::<U256>::None],
+        "whitelist_expiries" => vec![Option::<u64>::None],
+    };
+
+    let result = context.call(admin, "set_whitelist_entries", args);
+
+    assert_eq!(result, Err(ApiError::from(Error::WrongArguments)));
+}
@@ -7,7 +7,7 @@ const ADMINS_DICT: &str = "admins";
 
 pub trait AdminControl<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(&mut self) {
-        Admins::init();
+        Admins::init(self.storage());
     }
 
     fn add_admin(&mut self, address: Key) {
@@ -17,11 +17,11 @@ pub trait AdminControl<Storage: ContractStorage>: ContractContext<Storage> {
 
     fn disable_admin(&mut self, address: Key) {
         self.assert_caller_is_admin();
-        Admins::instance().disable_admin(&address);
+        Admins::instance(self.storage()).disable_admin(&address);
     }
 
     fn add_admin_without_checked(&mut self, address: Key) {
-        Admins::instance().add_admin(&address);
+        Admins::instance(self.storage()).add_admin(&address);
     }
 
     fn assert_caller_is_admin(&self) {
@@ -32,22 +32,22 @@ pub trait AdminControl<Storage: ContractStorage>: ContractContext<Storage> {
     }
 
     fn is_admin(&self, address: Key) -> bool {
-        Admins::instance().is_admin(&address)
+        Admins::instance(self.storage()).is_admin(&address)
     }
 }
 
-struct Admins {
-    dict: Dict,
+struct Admins<'a, S: ContractStorage> {
+    dict: Dict<'a, S>,
 }
 
-impl Admins {
-    pub fn instance() -> Admins {
+impl<'a, S: ContractStorage> Admins<'a, S> {
+    pub fn instance(storage: &'a S) -> Admins<'a, S> {
         Admins {
-            dict: Dict::instance(ADMINS_DICT),
+            dict: Dict::instance(storage, ADMINS_DICT),
         }
     }
-    pub fn init() {
-        Dict::init(ADMINS_DICT);
+    pub fn init(storage: &S) {
+        Dict::init(storage, ADMINS_DICT);
     }
 
     pub fn is_admin(&self, key: &Key) -> bool {
@@ -1,11 +1,78 @@
 use alloc::vec::Vec;
 use core::cell::OnceCell;
 
-use casper_contract::contract_api::runtime;
-use casper_types::system::CallStackElement;
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_event_standard::Schemas;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{FromBytes, ToBytes},
+    system::CallStackElement,
+    ApiError, CLTyped, ContractPackageHash, Key, RuntimeArgs, URef, U512,
+};
 
+/// The environment a contract runs in: its call stack, named keys, storage, purses and the
+/// contracts it calls. Contract logic goes through it so it can also run natively against
+/// `MockContractStorage`.
 pub trait ContractStorage {
     fn call_stack(&self) -> &[CallStackElement];
+
+    /// Returns the block time in milliseconds.
+    fn block_time(&self) -> u64;
+
+    fn get_named_key(&self, name: &str) -> Option<Key>;
+
+    fn put_named_key(&self, name: &str, key: Key);
+
+    fn remove_named_key(&self, name: &str);
+
+    fn read<T: CLTyped + FromBytes>(&self, uref: URef) -> Option<T>;
+
+    fn write<T: CLTyped + ToBytes>(&self, uref: URef, value: T);
+
+    fn new_uref<T: CLTyped + ToBytes>(&self, value: T) -> URef;
+
+    /// Creates a dictionary stored under the named key.
+    fn new_dictionary(&self, name: &str) -> URef;
+
+    fn dictionary_get<T: CLTyped + FromBytes>(&self, dictionary: URef, key: &str) -> Option<T>;
+
+    fn dictionary_put<T: CLTyped + ToBytes>(&self, dictionary: URef, key: &str, value: T);
+
+    fn blake2b(&self, bytes: &[u8]) -> [u8; 32];
+
+    fn create_purse(&self) -> URef;
+
+    fn purse_balance(&self, purse: URef) -> Option<U512>;
+
+    fn transfer_from_purse_to_account(
+        &self,
+        source: URef,
+        target: AccountHash,
+        amount: U512,
+    ) -> Result<(), ApiError>;
+
+    fn transfer_from_purse_to_purse(
+        &self,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(), ApiError>;
+
+    /// Calls the entry point of the latest version of the contract package.
+    fn call_versioned_contract<T: CLTyped + FromBytes>(
+        &self,
+        package_hash: ContractPackageHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> T;
+
+    /// Registers the event schemas of the contract.
+    fn init_events(&self, schemas: Schemas);
+
+    fn emit_event<T: ToBytes>(&self, event: T);
 }
 
 #[derive(Default)]
@@ -18,4 +85,91 @@ impl ContractStorage for OnChainContractStorage {
         let call_stack = self.call_stack.get_or_init(runtime::get_call_stack);
         call_stack.as_slice()
     }
+
+    fn block_time(&self) -> u64 {
+        runtime::get_blocktime().into()
+    }
+
+    fn get_named_key(&self, name: &str) -> Option<Key> {
+        runtime::get_key(name)
+    }
+
+    fn put_named_key(&self, name: &str, key: Key) {
+        runtime::put_key(name, key);
+    }
+
+    fn remove_named_key(&self, name: &str) {
+        runtime::remove_key(name);
+    }
+
+    fn read<T: CLTyped + FromBytes>(&self, uref: URef) -> Option<T> {
+        storage::read(uref).unwrap_or_revert()
+    }
+
+    fn write<T: CLTyped + ToBytes>(&self, uref: URef, value: T) {
+        storage::write(uref, value);
+    }
+
+    fn new_uref<T: CLTyped + ToBytes>(&self, value: T) -> URef {
+        storage::new_uref(value)
+    }
+
+    fn new_dictionary(&self, name: &str) -> URef {
+        storage::new_dictionary(name).unwrap_or_revert()
+    }
+
+    fn dictionary_get<T: CLTyped + FromBytes>(&self, dictionary: URef, key: &str) -> Option<T> {
+        storage::dictionary_get(dictionary, key).unwrap_or_revert()
+    }
+
+    fn dictionary_put<T: CLTyped + ToBytes>(&self, dictionary: URef, key: &str, value: T) {
+        storage::dictionary_put(dictionary, key, value);
+    }
+
+    fn blake2b(&self, bytes: &[u8]) -> [u8; 32] {
+        runtime::blake2b(bytes)
+    }
+
+    fn create_purse(&self) -> URef {
+        system::create_purse()
+    }
+
+    fn purse_balance(&self, purse: URef) -> Option<U512> {
+        system::get_purse_balance(purse)
+    }
+
+    fn transfer_from_purse_to_account(
+        &self,
+        source: URef,
+        target: AccountHash,
+        amount: U512,
+    ) -> Result<(), ApiError> {
+        system::transfer_from_purse_to_account(source, target, amount, None).map(|_| ())
+    }
+
+    fn transfer_from_purse_to_purse(
+        &self,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(), ApiError> {
+        system::transfer_from_purse_to_purse(source, target, amount, None)
+    }
+
+    fn call_versioned_contract<T: CLTyped + FromBytes>(
+        &self,
+        package_hash: ContractPackageHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> T {
+        runtime::call_versioned_contract(package_hash, None, entry_point, args)
+    }
+
+    fn init_events(&self, schemas: Schemas) {
+        casper_event_standard::init(schemas);
+    }
+
+    fn emit_event<T: ToBytes>(&self, event: T) {
+        casper_event_standard::emit(event);
+    }
 }
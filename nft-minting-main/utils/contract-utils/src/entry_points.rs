@@ -0,0 +1,84 @@
+/// Declares the entry points of a contract once.
+///
+/// Every `fn` expands to a `#[no_mangle]` function reading each argument from the named arg
+/// after `=` as its `NamedArg` type, running the body and returning its value with
+/// `runtime::ret` when it declares one. The function named first returns the matching
+/// `EntryPoints`, with parameters typed by `NamedArg::cl_type`. Entry points are public
+/// unless restricted to user groups with `#[groups(..)]`.
+///
+/// ```ignore
+/// entry_points! {
+///     fn get_entry_points();
+///
+///     #[groups(CONSTRUCTOR_GROUP)]
+///     fn constructor(admin: Key = ARG_ADMIN) {
+///         Contract::default().constructor(admin);
+///     }
+///
+///     fn get_fee(discount: Option<U256> = ARG_DISCOUNT) -> U256 {
+///         Contract::default().get_fee(discount)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! entry_points {
+    (
+        fn $list:ident();
+        $(
+            $(#[groups($($group:expr),+ $(,)?)])?
+            fn $name:ident($($arg:ident: $ty:ty = $arg_name:expr),* $(,)?) $(-> $ret:ty)? $body:block
+        )*
+    ) => {
+        $(
+            #[no_mangle]
+            pub extern "C" fn $name() {
+                $(let $arg = <$ty as $crate::NamedArg>::get($arg_name);)*
+                $crate::entry_points!(@call $body $(, $ret)?);
+            }
+        )*
+
+        fn $list() -> $crate::__private::EntryPoints {
+            let mut entry_points = $crate::__private::EntryPoints::new();
+            $(
+                entry_points.add_entry_point($crate::__private::EntryPoint::new(
+                    stringify!($name),
+                    $crate::__private::vec![
+                        $($crate::__private::Parameter::new(
+                            $arg_name,
+                            <$ty as $crate::NamedArg>::cl_type(),
+                        )),*
+                    ],
+                    $crate::entry_points!(@ret_type $($ret)?),
+                    $crate::entry_points!(@access $($($group),+)?),
+                    $crate::__private::EntryPointType::Contract,
+                ));
+            )*
+            entry_points
+        }
+    };
+    (@call $body:block) => {
+        $body
+    };
+    (@call $body:block, $ret:ty) => {{
+        let result: $ret = $body;
+        $crate::__private::runtime::ret(
+            $crate::__private::UnwrapOrRevert::unwrap_or_revert(
+                $crate::__private::CLValue::from_t(result),
+            ),
+        )
+    }};
+    (@ret_type) => {
+        $crate::__private::CLType::Unit
+    };
+    (@ret_type $ret:ty) => {
+        <$ret as $crate::__private::CLTyped>::cl_type()
+    };
+    (@access) => {
+        $crate::__private::EntryPointAccess::Public
+    };
+    (@access $($group:expr),+) => {
+        $crate::__private::EntryPointAccess::Groups($crate::__private::vec![
+            $($crate::__private::Group::new($group)),+
+        ])
+    };
+}
@@ -7,8 +7,28 @@ mod admin_control;
 mod contract_context;
 mod contract_storage;
 mod data;
+mod entry_points;
+#[cfg(feature = "test-support")]
+mod mock_contract_storage;
+mod named_args;
 
 pub use admin_control::AdminControl;
 pub use contract_context::ContractContext;
 pub use contract_storage::{ContractStorage, OnChainContractStorage};
 pub use data::{get_key, key_and_value_to_str, key_to_str, set_key, Dict};
+pub use named_args::{get_named_arg_size, get_optional_named_arg, NamedArg};
+#[cfg(feature = "test-support")]
+pub use mock_contract_storage::{
+    ContractCall, FakeCep78, MockContractStorage, FAKE_CEP78_PACKAGE_HASH,
+    MOCK_CONTRACT_PACKAGE_HASH,
+};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec;
+    pub use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+    pub use casper_types::{
+        CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+        Group, Parameter,
+    };
+}
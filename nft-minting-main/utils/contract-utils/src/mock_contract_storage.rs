@@ -0,0 +1,340 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cell::{Cell, Ref, RefCell};
+
+use casper_event_standard::Schemas;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    system::CallStackElement,
+    AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, Key,
+    RuntimeArgs, URef, URefAddr, U512,
+};
+
+use crate::ContractStorage;
+
+/// The package hash of the contract running on `MockContractStorage`.
+pub const MOCK_CONTRACT_PACKAGE_HASH: ContractPackageHash = ContractPackageHash::new([0xaa; 32]);
+
+/// The package hash answered by the fake CEP-78 collection of `MockContractStorage`.
+pub const FAKE_CEP78_PACKAGE_HASH: ContractPackageHash = ContractPackageHash::new([0x78; 32]);
+
+/// A call to another contract recorded by `MockContractStorage`.
+#[derive(Debug, Clone)]
+pub struct ContractCall {
+    pub package_hash: ContractPackageHash,
+    pub entry_point: String,
+    pub args: RuntimeArgs,
+    /// The number of events emitted before the call.
+    pub events_before: usize,
+}
+
+/// A CEP-78 collection answering `register_owner`, `mint` and `balance_of`, where token ids
+/// are the mint order.
+#[derive(Debug, Default)]
+pub struct FakeCep78 {
+    tokens: Vec<(Key, String)>,
+}
+
+impl FakeCep78 {
+    pub fn total_minted(&self) -> u64 {
+        self.tokens.len() as u64
+    }
+
+    pub fn owner_of(&self, token_id: u64) -> Option<Key> {
+        self.tokens.get(token_id as usize).map(|(owner, _)| *owner)
+    }
+
+    pub fn metadata_of(&self, token_id: u64) -> Option<&str> {
+        self.tokens
+            .get(token_id as usize)
+            .map(|(_, metadata)| metadata.as_str())
+    }
+
+    pub fn balance_of(&self, owner: &Key) -> u64 {
+        self.tokens.iter().filter(|(token_owner, _)| token_owner == owner).count() as u64
+    }
+
+    fn call(&mut self, entry_point: &str, args: &RuntimeArgs) -> CLValue {
+        match entry_point {
+            "register_owner" => {
+                CLValue::from_t((String::new(), URef::new([0; 32], AccessRights::READ))).unwrap()
+            }
+            "mint" => {
+                let token_owner: Key = named_arg(args, "token_owner");
+                let token_meta_data: String = named_arg(args, "token_meta_data");
+                let token_id = self.total_minted();
+                self.tokens.push((token_owner, token_meta_data));
+                CLValue::from_t((String::new(), token_owner, token_id.to_string())).unwrap()
+            }
+            "balance_of" => {
+                let token_owner: Key = named_arg(args, "token_owner");
+                CLValue::from_t(self.balance_of(&token_owner)).unwrap()
+            }
+            _ => panic!("unknown CEP-78 entry point {}", entry_point),
+        }
+    }
+}
+
+/// An in-memory `ContractStorage` running contract logic natively. Calls to
+/// `FAKE_CEP78_PACKAGE_HASH` go to a `FakeCep78`, calls to other contracts are only recorded
+/// and return unit.
+pub struct MockContractStorage {
+    call_stack: Vec<CallStackElement>,
+    block_time: Cell<u64>,
+    next_addr: Cell<u64>,
+    named_keys: RefCell<BTreeMap<String, Key>>,
+    values: RefCell<BTreeMap<URefAddr, Vec<u8>>>,
+    dictionaries: RefCell<BTreeMap<(URefAddr, String), Vec<u8>>>,
+    purses: RefCell<BTreeMap<URefAddr, U512>>,
+    accounts: RefCell<BTreeMap<AccountHash, U512>>,
+    cep78: RefCell<FakeCep78>,
+    calls: RefCell<Vec<ContractCall>>,
+    events: RefCell<Vec<Bytes>>,
+}
+
+impl Default for MockContractStorage {
+    fn default() -> Self {
+        MockContractStorage::new(Key::Account(AccountHash::default()))
+    }
+}
+
+impl MockContractStorage {
+    pub fn new(caller: Key) -> Self {
+        let mut storage = MockContractStorage {
+            call_stack: Vec::new(),
+            block_time: Cell::new(0),
+            next_addr: Cell::new(0),
+            named_keys: RefCell::default(),
+            values: RefCell::default(),
+            dictionaries: RefCell::default(),
+            purses: RefCell::default(),
+            accounts: RefCell::default(),
+            cep78: RefCell::default(),
+            calls: RefCell::default(),
+            events: RefCell::default(),
+        };
+        storage.set_caller(caller);
+        storage
+    }
+
+    /// Makes the account or contract package the caller of the contract.
+    pub fn set_caller(&mut self, caller: Key) {
+        let caller = match caller {
+            Key::Account(account_hash) => CallStackElement::Session { account_hash },
+            Key::Hash(hash) => CallStackElement::StoredContract {
+                contract_package_hash: ContractPackageHash::new(hash),
+                contract_hash: ContractHash::new(hash),
+            },
+            _ => panic!("caller must be an account or a contract package"),
+        };
+        self.call_stack = vec![
+            caller,
+            CallStackElement::StoredContract {
+                contract_package_hash: MOCK_CONTRACT_PACKAGE_HASH,
+                contract_hash: ContractHash::new(MOCK_CONTRACT_PACKAGE_HASH.value()),
+            },
+        ];
+    }
+
+    pub fn set_block_time(&self, block_time: u64) {
+        self.block_time.set(block_time);
+    }
+
+    pub fn has_named_key(&self, name: &str) -> bool {
+        self.named_keys.borrow().contains_key(name)
+    }
+
+    /// Creates a purse holding the amount, as if it was funded by a session.
+    pub fn create_funded_purse(&self, amount: U512) -> URef {
+        let purse = self.create_purse();
+        self.purses.borrow_mut().insert(purse.addr(), amount);
+        purse
+    }
+
+    /// Returns the CSPR received by the account.
+    pub fn account_balance(&self, account_hash: AccountHash) -> U512 {
+        self.accounts
+            .borrow()
+            .get(&account_hash)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn cep78(&self) -> Ref<FakeCep78> {
+        self.cep78.borrow()
+    }
+
+    /// Returns the calls made to contracts other than the fake CEP-78.
+    pub fn calls(&self) -> Ref<Vec<ContractCall>> {
+        self.calls.borrow()
+    }
+
+    /// Returns the serialized events emitted so far.
+    pub fn events(&self) -> Ref<Vec<Bytes>> {
+        self.events.borrow()
+    }
+
+    fn new_addr(&self) -> URefAddr {
+        let next_addr = self.next_addr.get() + 1;
+        self.next_addr.set(next_addr);
+        let mut addr = [0; 32];
+        addr[..8].copy_from_slice(&next_addr.to_le_bytes());
+        addr
+    }
+
+    fn withdraw(&self, source: URef, amount: U512) -> Result<(), ApiError> {
+        let mut purses = self.purses.borrow_mut();
+        let balance = purses.get_mut(&source.addr()).ok_or(ApiError::InvalidPurse)?;
+        if *balance < amount {
+            return Err(ApiError::Transfer);
+        }
+        *balance -= amount;
+        Ok(())
+    }
+}
+
+impl ContractStorage for MockContractStorage {
+    fn call_stack(&self) -> &[CallStackElement] {
+        self.call_stack.as_slice()
+    }
+
+    fn block_time(&self) -> u64 {
+        self.block_time.get()
+    }
+
+    fn get_named_key(&self, name: &str) -> Option<Key> {
+        self.named_keys.borrow().get(name).copied()
+    }
+
+    fn put_named_key(&self, name: &str, key: Key) {
+        self.named_keys.borrow_mut().insert(name.to_string(), key);
+    }
+
+    fn remove_named_key(&self, name: &str) {
+        self.named_keys.borrow_mut().remove(name);
+    }
+
+    fn read<T: CLTyped + FromBytes>(&self, uref: URef) -> Option<T> {
+        self.values
+            .borrow()
+            .get(&uref.addr())
+            .map(|bytes| bytesrepr::deserialize_from_slice(bytes).unwrap())
+    }
+
+    fn write<T: CLTyped + ToBytes>(&self, uref: URef, value: T) {
+        self.values
+            .borrow_mut()
+            .insert(uref.addr(), value.into_bytes().unwrap());
+    }
+
+    fn new_uref<T: CLTyped + ToBytes>(&self, value: T) -> URef {
+        let uref = URef::new(self.new_addr(), AccessRights::READ_ADD_WRITE);
+        self.write(uref, value);
+        uref
+    }
+
+    fn new_dictionary(&self, name: &str) -> URef {
+        if self.has_named_key(name) {
+            panic!("named key {} already exists", name);
+        }
+        let uref = URef::new(self.new_addr(), AccessRights::READ_ADD_WRITE);
+        self.put_named_key(name, uref.into());
+        uref
+    }
+
+    fn dictionary_get<T: CLTyped + FromBytes>(&self, dictionary: URef, key: &str) -> Option<T> {
+        self.dictionaries
+            .borrow()
+            .get(&(dictionary.addr(), key.to_string()))
+            .map(|bytes| bytesrepr::deserialize_from_slice(bytes).unwrap())
+    }
+
+    fn dictionary_put<T: CLTyped + ToBytes>(&self, dictionary: URef, key: &str, value: T) {
+        self.dictionaries
+            .borrow_mut()
+            .insert((dictionary.addr(), key.to_string()), value.into_bytes().unwrap());
+    }
+
+    fn blake2b(&self, bytes: &[u8]) -> [u8; 32] {
+        casper_types::crypto::blake2b(bytes)
+    }
+
+    fn create_purse(&self) -> URef {
+        let purse = URef::new(self.new_addr(), AccessRights::READ_ADD_WRITE);
+        self.purses.borrow_mut().insert(purse.addr(), U512::zero());
+        purse
+    }
+
+    fn purse_balance(&self, purse: URef) -> Option<U512> {
+        self.purses.borrow().get(&purse.addr()).copied()
+    }
+
+    fn transfer_from_purse_to_account(
+        &self,
+        source: URef,
+        target: AccountHash,
+        amount: U512,
+    ) -> Result<(), ApiError> {
+        self.withdraw(source, amount)?;
+        *self.accounts.borrow_mut().entry(target).or_default() += amount;
+        Ok(())
+    }
+
+    fn transfer_from_purse_to_purse(
+        &self,
+        source: URef,
+        target: URef,
+        amount: U512,
+    ) -> Result<(), ApiError> {
+        if self.purse_balance(target).is_none() {
+            return Err(ApiError::InvalidPurse);
+        }
+        self.withdraw(source, amount)?;
+        *self.purses.borrow_mut().entry(target.addr()).or_default() += amount;
+        Ok(())
+    }
+
+    fn call_versioned_contract<T: CLTyped + FromBytes>(
+        &self,
+        package_hash: ContractPackageHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) -> T {
+        let result = if package_hash == FAKE_CEP78_PACKAGE_HASH {
+            self.cep78.borrow_mut().call(entry_point, &args)
+        } else {
+            self.calls.borrow_mut().push(ContractCall {
+                package_hash,
+                entry_point: entry_point.to_string(),
+                args,
+                events_before: self.events.borrow().len(),
+            });
+            CLValue::unit()
+        };
+        result.into_t().unwrap()
+    }
+
+    fn init_events(&self, _schemas: Schemas) {}
+
+    fn emit_event<T: ToBytes>(&self, event: T) {
+        self.events
+            .borrow_mut()
+            .push(Bytes::from(event.into_bytes().unwrap()));
+    }
+}
+
+fn named_arg<T: CLTyped + FromBytes>(args: &RuntimeArgs, name: &str) -> T {
+    args.get(name).cloned().unwrap().into_t().unwrap()
+}
+
+/// Turns reverts into panics carrying the error code when running natively.
+#[cfg(not(target_arch = "wasm32"))]
+#[no_mangle]
+pub extern "C" fn casper_revert(status: u32) -> ! {
+    panic!("reverted with code {}", status)
+}
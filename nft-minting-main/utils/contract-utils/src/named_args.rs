@@ -0,0 +1,79 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use casper_contract::{contract_api::runtime, ext_ffi};
+use casper_types::{
+    api_error, bytesrepr::FromBytes, ApiError, CLType, CLTyped, Key, URef, U256, U512,
+};
+
+/// A type an entry point argument is read as, declared in the entry point with `cl_type`.
+pub trait NamedArg: Sized {
+    fn cl_type() -> CLType;
+
+    /// Reads the argument, reverting when it is required and missing or cannot be parsed.
+    fn get(name: &str) -> Self;
+}
+
+macro_rules! impl_required_named_arg {
+    ($($ty:ty),*) => {
+        $(
+            impl NamedArg for $ty {
+                fn cl_type() -> CLType {
+                    <$ty as CLTyped>::cl_type()
+                }
+
+                fn get(name: &str) -> Self {
+                    runtime::get_named_arg(name)
+                }
+            }
+        )*
+    };
+}
+
+impl_required_named_arg!(bool, u8, u32, u64, U256, U512, Key, URef, String, [u8; 32]);
+
+impl<T: CLTyped + FromBytes> NamedArg for Vec<T> {
+    fn cl_type() -> CLType {
+        CLType::List(Box::new(<T as CLTyped>::cl_type()))
+    }
+
+    fn get(name: &str) -> Self {
+        runtime::get_named_arg(name)
+    }
+}
+
+/// An optional argument is `None` when left out, otherwise it is passed as an `Option<T>`.
+impl<T: NamedArg + CLTyped + FromBytes> NamedArg for Option<T> {
+    fn cl_type() -> CLType {
+        CLType::Option(Box::new(<T as NamedArg>::cl_type()))
+    }
+
+    fn get(name: &str) -> Self {
+        get_named_arg_size(name).and_then(|_| runtime::get_named_arg::<Option<T>>(name))
+    }
+}
+
+pub fn get_named_arg_size(name: &str) -> Option<usize> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => runtime::revert(e),
+    }
+}
+
+/// Reads an argument left out or passed as a plain `T`, as session code arguments are.
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+    if let Some(arg_size) = get_named_arg_size(name) {
+        if arg_size > 0 {
+            return Some(runtime::get_named_arg::<T>(name));
+        }
+    }
+    None
+}